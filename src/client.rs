@@ -1,8 +1,48 @@
 //! Helix client
 
+// Modules
+mod channels;
+mod commercial;
+mod envelope;
+#[cfg(test)]
+mod mock;
+mod rate_limit;
+mod retry;
+
+// Exports
+pub use channels::ChannelsInfo;
+pub use commercial::Commercial;
+pub use envelope::HelixEnvelope;
+pub use rate_limit::RateLimit;
+pub use retry::{RetryPolicy, SendErrorKind};
+
 // Imports
-use crate::{HelixRequest, HelixResponse, HttpMethod, OAuthRequest, OAuthResponse};
+use crate::{
+	helix_url, oauth_url,
+	request::oauth::{device, revoke, token, validate, Scope},
+	response::ResponseError,
+	HelixRequest, HttpMethod, OAuthRequest, OAuthResponse, PaginatedRequest,
+};
+use futures::{stream, Stream};
 use reqwest as req;
+use std::{
+	collections::{HashMap, VecDeque},
+	time::{Duration, Instant},
+};
+
+/// How long before an app access token expires we mint a new one
+const APP_TOKEN_EXPIRY_MARGIN: Duration = Duration::from_secs(60);
+
+/// Minimum time to wait when Helix says our rate limit is empty
+///
+/// Used when it doesn't say until when, or says it's already been refilled.
+const RATE_LIMIT_FALLBACK_WAIT: Duration = Duration::from_secs(1);
+
+/// Maximum number of times to retry a request when Helix says our rate limit is empty
+const MAX_RATE_LIMIT_RETRIES: u32 = 5;
+
+/// How much to increase the polling interval by when twitch asks us to slow down
+const DEVICE_SLOW_DOWN_INTERVAL: Duration = Duration::from_secs(5);
 
 /// A client to make requests to Helix with.
 ///
@@ -10,13 +50,102 @@ use reqwest as req;
 /// This allows you to do a [`OAuthRequest`] for validating
 /// this token and getting a client id, which may then be used
 /// to make [`HelixRequest`]
+///
+/// Alternatively, the client may be initialized given a client id
+/// and secret, in which case it will mint an app access token, and
+/// mint a new one before it expires.
+///
+/// If the token's scopes are known, requests that require any scopes the token
+/// doesn't have fail before being sent, see [`Client::with_scopes`] and [`Client::validate`].
+///
+/// The client keeps track of the rate limit of each token, and may be
+/// set to wait until it's refilled instead of failing, see [`Client::with_rate_limit_wait`].
+///
+/// Helix requests that fail due to transient failures may be retried,
+/// see [`Client::with_retry_policy`].
+///
+/// When Helix rejects the token, the client will attempt to get a new
+/// one and retry the request once. For user access tokens, this requires
+/// a refresh token, see [`Client::with_refresh`].
+///
+/// By default, requests are made to twitch, but the base urls may be
+/// changed to make requests elsewhere, such as to a local mock server.
 #[derive(Clone, Debug)]
 pub struct Client {
 	/// Underlying client
 	client: req::Client,
 
+	/// Authentication
+	auth: Auth,
+
+	/// Scopes of the current token, if known
+	scopes: Option<Vec<Scope>>,
+
+	/// Rate limit of each token
+	rate_limits: HashMap<String, RateLimit>,
+
+	/// If we should wait for the rate limit to be refilled, instead of failing
+	rate_limit_wait: bool,
+
+	/// Retry policy, if any
+	retry_policy: Option<RetryPolicy>,
+
+	/// Base helix url
+	helix_base: url::Url,
+
+	/// Base oauth url
+	oauth_base: url::Url,
+}
+
+/// Client authentication
+#[derive(Clone, Debug)]
+enum Auth {
+	/// User access token
+	User {
+		/// OAuth token
+		oauth: String,
+
+		/// Refresh credentials, if any
+		refresh: Option<Refresh>,
+	},
+
+	/// App access token
+	App {
+		/// Client id
+		client_id: String,
+
+		/// Client secret
+		client_secret: String,
+
+		/// Current token, if any has been minted yet
+		token: Option<AppToken>,
+	},
+
+	/// Revoked token
+	Revoked,
+}
+
+/// Credentials to refresh a user access token
+#[derive(Clone, Debug)]
+struct Refresh {
+	/// Client id
+	client_id: String,
+
+	/// Client secret
+	client_secret: String,
+
+	/// Refresh token
+	token: String,
+}
+
+/// An app access token
+#[derive(Clone, Debug)]
+struct AppToken {
 	/// OAuth token
 	oauth: String,
+
+	/// Expiration, if known
+	expires_at: Option<Instant>,
 }
 
 /// Error type for [`Client::request_oauth`] and [`Client::request_helix`]
@@ -26,67 +155,942 @@ pub enum RequestError {
 	#[error("Unable to send request")]
 	Send(#[source] req::Error),
 
+	/// Unable to receive response
+	#[error("Unable to receive response")]
+	Receive(#[source] req::Error),
+
 	/// Unable to parse response
 	#[error("Unable to parse response")]
-	Parse(#[source] req::Error),
+	Parse(#[source] serde_json::Error),
+
+	/// Helix returned an error
+	#[error("Helix returned an error")]
+	Helix(#[source] ResponseError),
+
+	/// OAuth returned an error
+	#[error("OAuth returned an error")]
+	OAuth(#[source] ResponseError),
+
+	/// Client's token was revoked
+	#[error("Client's token was revoked")]
+	Revoked,
+
+	/// Client's token is missing a scope required by the request
+	#[error("Missing scope: {0}")]
+	MissingScope(Scope),
+}
+
+/// Error type for [`Client::poll_device_token`]
+#[derive(Debug, thiserror::Error)]
+pub enum DeviceTokenError {
+	/// Unable to request token
+	#[error("Unable to request token")]
+	Request(#[source] RequestError),
+
+	/// Device code expired before the user authorized it
+	#[error("Device code expired before the user authorized it")]
+	Expired,
+
+	/// Authorization was denied
+	#[error("Authorization was denied")]
+	Denied(#[source] ResponseError),
 }
 
 impl Client {
 	/// Creates a new client given an oauth token
 	#[must_use]
 	pub fn new(oauth: String) -> Self {
+		Self::with_auth(Auth::User { oauth, refresh: None })
+	}
+
+	/// Creates a new client that uses app access tokens given
+	/// it's client id and secret.
+	///
+	/// The first token is only minted on the first request.
+	#[must_use]
+	pub fn new_app(client_id: String, client_secret: String) -> Self {
+		Self::with_auth(Auth::App {
+			client_id,
+			client_secret,
+			token: None,
+		})
+	}
+
+	/// Creates a new client given it's authentication
+	fn with_auth(auth: Auth) -> Self {
 		Self {
 			client: req::Client::new(),
-			oauth,
+			auth,
+			scopes: None,
+			rate_limits: HashMap::new(),
+			rate_limit_wait: false,
+			retry_policy: None,
+			helix_base: helix_url!(),
+			oauth_base: oauth_url!(),
+		}
+	}
+
+	/// Sets the base url for all helix requests
+	///
+	/// # Panics
+	/// Panics if `helix_base` cannot be a base, see [`url::Url::cannot_be_a_base`].
+	#[must_use]
+	pub fn with_helix_base(self, helix_base: url::Url) -> Self {
+		assert!(!helix_base.cannot_be_a_base(), "Helix base url cannot be a base");
+		Self { helix_base, ..self }
+	}
+
+	/// Sets the base url for all oauth requests
+	///
+	/// # Panics
+	/// Panics if `oauth_base` cannot be a base, see [`url::Url::cannot_be_a_base`].
+	#[must_use]
+	pub fn with_oauth_base(self, oauth_base: url::Url) -> Self {
+		assert!(!oauth_base.cannot_be_a_base(), "OAuth base url cannot be a base");
+		Self { oauth_base, ..self }
+	}
+
+	/// Sets the credentials to refresh the user access token with
+	///
+	/// Once set, whenever Helix rejects the current token, it will be refreshed
+	/// and the request retried once.
+	///
+	/// This has no effect on clients using app access tokens, as those will
+	/// mint a new token instead.
+	#[must_use]
+	pub fn with_refresh(self, client_id: String, client_secret: String, refresh_token: String) -> Self {
+		match self.auth {
+			Auth::User { oauth, .. } => Self {
+				auth: Auth::User {
+					oauth,
+					refresh: Some(Refresh {
+						client_id,
+						client_secret,
+						token: refresh_token,
+					}),
+				},
+				..self
+			},
+			Auth::App { .. } | Auth::Revoked => self,
+		}
+	}
+
+	/// Sets the scopes of the current token
+	///
+	/// These are also set by [`Client::validate`], as well as whenever
+	/// the client gets a new token.
+	#[must_use]
+	pub fn with_scopes(self, scopes: Vec<Scope>) -> Self {
+		Self {
+			scopes: Some(scopes),
+			..self
+		}
+	}
+
+	/// Returns the scopes of the current token, if known
+	#[must_use]
+	pub fn scopes(&self) -> Option<&[Scope]> {
+		self.scopes.as_deref()
+	}
+
+	/// Sets if the client should wait for the rate limit to be refilled
+	///
+	/// When set, instead of failing, requests will wait until the rate limit
+	/// is refilled if it's empty, or if Helix returns `429 Too Many Requests`,
+	/// after which they will be retried, up to 5 times.
+	#[must_use]
+	pub fn with_rate_limit_wait(self, rate_limit_wait: bool) -> Self {
+		Self { rate_limit_wait, ..self }
+	}
+
+	/// Sets the retry policy for helix requests
+	///
+	/// By default, requests aren't retried.
+	#[must_use]
+	pub fn with_retry_policy(self, retry_policy: RetryPolicy) -> Self {
+		Self {
+			retry_policy: Some(retry_policy),
+			..self
+		}
+	}
+
+	/// Returns the rate limit of the current token, if known
+	#[must_use]
+	pub fn rate_limit(&self) -> Option<RateLimit> {
+		let oauth = match &self.auth {
+			Auth::User { oauth, .. }
+			| Auth::App {
+				token: Some(AppToken { oauth, .. }),
+				..
+			} => oauth,
+			Auth::App { token: None, .. } | Auth::Revoked => return None,
+		};
+
+		self.rate_limits.get(oauth).copied()
+	}
+
+	/// Returns the current refresh token, if any
+	///
+	/// As twitch may return a new refresh token whenever the user access
+	/// token is refreshed, this should be checked after each request if the
+	/// refresh token is to be stored.
+	#[must_use]
+	pub fn refresh_token(&self) -> Option<&str> {
+		match &self.auth {
+			Auth::User { refresh: Some(refresh), .. } => Some(&refresh.token),
+			_ => None,
+		}
+	}
+
+	/// Returns the base url for all helix requests
+	#[must_use]
+	pub fn helix_base(&self) -> &url::Url {
+		&self.helix_base
+	}
+
+	/// Returns the base url for all oauth requests
+	#[must_use]
+	pub fn oauth_base(&self) -> &url::Url {
+		&self.oauth_base
+	}
+
+	/// Returns the current oauth token
+	///
+	/// If using app access tokens, a new one is minted if
+	/// none exist yet or if the current one is about to expire.
+	pub async fn oauth(&mut self) -> Result<String, RequestError> {
+		match &self.auth {
+			Auth::User { oauth, .. } => Ok(oauth.clone()),
+			Auth::Revoked => Err(RequestError::Revoked),
+
+			// If we have an app token that won't expire soon, return it
+			Auth::App { token: Some(token), .. }
+				if token
+					.expires_at
+					.map_or(true, |expires_at| Instant::now() + APP_TOKEN_EXPIRY_MARGIN < expires_at) =>
+			{
+				Ok(token.oauth.clone())
+			}
+
+			// Else mint a new one
+			Auth::App { .. } => self.mint_app_token().await,
+		}
+	}
+
+	/// Gets a new oauth token
+	///
+	/// If using app access tokens, a new one is minted, else if using
+	/// user access tokens, it is refreshed, if refresh credentials exist.
+	///
+	/// Returns if a new token was gotten.
+	pub async fn refresh_oauth(&mut self) -> Result<bool, RequestError> {
+		let (oauth, refresh) = match &mut self.auth {
+			Auth::User {
+				oauth,
+				refresh: Some(refresh),
+			} => (oauth, refresh),
+			Auth::User { refresh: None, .. } => return Ok(false),
+			Auth::App { .. } => return self.mint_app_token().await.map(|_| true),
+			Auth::Revoked => return Err(RequestError::Revoked),
+		};
+
+		// Refresh the token
+		let request = token::Request::refresh_token(refresh.client_id.as_str(), refresh.client_secret.as_str(), refresh.token.as_str());
+		let response = Self::send_oauth(&self.client, &self.oauth_base, &request, None)
+			.await?
+			.into_result()
+			.map_err(RequestError::OAuth)?;
+
+		// And replace the previous tokens
+		*oauth = response.access_token;
+		if let Some(refresh_token) = response.refresh_token {
+			refresh.token = refresh_token;
 		}
+		self.scopes = Some(response.scope);
+
+		Ok(true)
+	}
+
+	/// Mints a new app access token
+	///
+	/// Does nothing if not using app access tokens.
+	async fn mint_app_token(&mut self) -> Result<String, RequestError> {
+		let (client_id, client_secret, token) = match &mut self.auth {
+			Auth::User { oauth, .. } => return Ok(oauth.clone()),
+			Auth::Revoked => return Err(RequestError::Revoked),
+			Auth::App {
+				client_id,
+				client_secret,
+				token,
+			} => (client_id, client_secret, token),
+		};
+
+		let request = token::Request::client_credentials(client_id.as_str(), client_secret.as_str());
+		let response = Self::send_oauth(&self.client, &self.oauth_base, &request, None)
+			.await?
+			.into_result()
+			.map_err(RequestError::OAuth)?;
+
+		// Note: We count the expiration from after receiving the response,
+		//       which is fine, as we mint a new token ahead of time anyway.
+		*token = Some(AppToken {
+			oauth: response.access_token.clone(),
+			expires_at: response.expires_in.map(|expires_in| Instant::now() + Duration::from_secs(expires_in)),
+		});
+		self.scopes = Some(response.scope);
+
+		Ok(response.access_token)
 	}
 
 	/// Performs an OAuth request to twitch
 	pub async fn request_oauth<R: OAuthRequest + Send + Sync>(&mut self, request: &R) -> Result<OAuthResponse<R::Response>, RequestError> {
-		// Get url
-		let url = request.url();
+		let oauth = self.oauth().await?;
+		Self::send_oauth(&self.client, &self.oauth_base, request, Some(&oauth)).await
+	}
+
+	/// Sends an OAuth request to twitch, with an optional oauth token
+	async fn send_oauth<R: OAuthRequest + Send + Sync>(
+		client: &req::Client, oauth_base: &url::Url, request: &R, oauth: Option<&str>,
+	) -> Result<OAuthResponse<R::Response>, RequestError> {
+		// Get url and form
+		let url = request.url_with_base(oauth_base);
+		let form = request.form();
 
 		// Build the request and send it
-		let response = self
-			.client
-			.get(url)
-			.header("Authorization", format!("OAuth {}", self.oauth))
-			.send()
-			.await
-			.map_err(RequestError::Send)?;
+		let mut request = client.request(Self::method(request.http_method()), url);
+		if let Some(oauth) = oauth {
+			request = request.header("Authorization", format!("OAuth {}", oauth));
+		}
+		if let Some(form) = form {
+			request = request.form(&form);
+		}
+		let response = request.send().await.map_err(RequestError::Send)?;
 
 		// Then parse the response
-		let output = response.json().await.map_err(RequestError::Parse)?;
+		Self::parse(response).await
+	}
+
+	/// Validates the current oauth token
+	///
+	/// The token's scopes are then kept, so requests that require
+	/// scopes the token doesn't have fail before being sent.
+	pub async fn validate(&mut self) -> Result<validate::Response, RequestError> {
+		let response = self.request_oauth(&validate::Request).await?.into_result().map_err(RequestError::OAuth)?;
+		self.scopes = Some(response.scopes.clone());
+
+		Ok(response)
+	}
+
+	/// Revokes the current oauth token
+	///
+	/// After this, the client becomes unusable, and every request
+	/// will fail with [`RequestError::Revoked`].
+	///
+	/// The client id must be the one the token was issued to.
+	pub async fn revoke(&mut self, client_id: &str) -> Result<(), RequestError> {
+		let oauth = match &self.auth {
+			Auth::User { oauth, .. }
+			| Auth::App {
+				token: Some(AppToken { oauth, .. }),
+				..
+			} => Some(oauth.as_str()),
+			Auth::App { token: None, .. } => None,
+			Auth::Revoked => return Err(RequestError::Revoked),
+		};
+
+		// Revoke the token, if we have any
+		if let Some(oauth) = oauth {
+			let request = revoke::Request::new(client_id, oauth);
+			Self::send_oauth(&self.client, &self.oauth_base, &request, None)
+				.await?
+				.into_result()
+				.map_err(RequestError::OAuth)?;
+		}
+
+		self.auth = Auth::Revoked;
+		Ok(())
+	}
+
+	/// Polls for a user access token using the device code flow
+	///
+	/// Given the response of a [`device::Request`], when it was received, and the
+	/// same client id and scopes used for it, polls twitch for the token, with the
+	/// interval given by twitch, until the user authorizes it, denies it, or the
+	/// device code expires.
+	///
+	/// The device code's expiration is counted from when it's response was received.
+	pub async fn poll_device_token(
+		&self, client_id: &str, device: &device::Response, received_at: Instant, scopes: Vec<Scope>,
+	) -> Result<token::Response, DeviceTokenError> {
+		let expires_at = received_at + Duration::from_secs(device.expires_in);
+		let mut interval = Duration::from_secs(device.interval);
+		let request = token::Request::device_code(client_id, device.device_code.as_str(), scopes);
 
-		Ok(output)
+		loop {
+			// Wait for the user and check if the device code is still valid
+			tokio::time::delay_for(interval).await;
+			if Instant::now() >= expires_at {
+				return Err(DeviceTokenError::Expired);
+			}
+
+			// Then check if the user already authorized it
+			let response = Self::send_oauth(&self.client, &self.oauth_base, &request, None)
+				.await
+				.map_err(DeviceTokenError::Request)?;
+			match response.into_result() {
+				Ok(token) => return Ok(token),
+				Err(err) if err.message == "authorization_pending" => (),
+				Err(err) if err.message == "slow_down" => interval += DEVICE_SLOW_DOWN_INTERVAL,
+				Err(err) if err.message == "invalid device code" => return Err(DeviceTokenError::Expired),
+				Err(err) => return Err(DeviceTokenError::Denied(err)),
+			}
+		}
 	}
 
 	/// Performs a request to Helix
+	///
+	/// The response is returned alongside it's http status and headers.
+	/// Whether it succeeded is decided by it's status, so responses without
+	/// a body, such as `204 No Content`, may be parsed into `()`.
 	pub async fn request_helix<R: HelixRequest + Send + Sync>(
 		&mut self, request: &R, client_id: &str,
-	) -> Result<HelixResponse<R::Response>, RequestError> {
-		// Get url
-		let url = request.url();
+	) -> Result<HelixEnvelope<R::Response, R::Extra>, RequestError> {
+		// If we know our scopes, check we have all the request requires
+		if let Some(scopes) = &self.scopes {
+			if let Some(scope) = request.required_scopes().iter().find(|scope| !scopes.contains(scope)) {
+				return Err(RequestError::MissingScope(scope.clone()));
+			}
+
+			// Note: If we're missing all alternatives, we report the first one.
+			let any_required_scopes = request.any_required_scopes();
+			if !any_required_scopes.is_empty() && !any_required_scopes.iter().any(|scope| scopes.contains(scope)) {
+				return Err(RequestError::MissingScope(any_required_scopes[0].clone()));
+			}
+		}
+
+		// Send the request
+		let response = self.send_helix(request, client_id).await?;
+
+		// If our token was rejected, try to get a new one and retry once
+		let response = if response.status() == req::StatusCode::UNAUTHORIZED && self.refresh_oauth().await? {
+			self.send_helix(request, client_id).await?
+		} else {
+			response
+		};
+
+		// Then parse the response
+		let status = response.status();
+		let headers = response.headers().clone();
+		let rate_limit = RateLimit::from_headers(&headers);
+		let body = response.bytes().await.map_err(RequestError::Receive)?;
+		let response = envelope::parse_body(status, &body).map_err(RequestError::Parse)?;
+
+		Ok(HelixEnvelope {
+			status,
+			headers,
+			rate_limit,
+			response,
+		})
+	}
+
+	/// Sends a request to Helix
+	///
+	/// If waiting for the rate limit, this will wait until it's refilled
+	/// before sending the request, and retry it if Helix says it's empty.
+	///
+	/// If a retry policy exists, transient failures are also retried.
+	async fn send_helix<R: HelixRequest + Send + Sync>(&mut self, request: &R, client_id: &str) -> Result<req::Response, RequestError> {
+		// Note: Retries due to the rate limit don't count towards the retry policy's attempts.
+		let mut attempt = 0;
+		let mut rate_limit_retries = 0;
+		loop {
+			// Get the oauth token and url
+			let oauth = self.oauth().await?;
+			let url = request.url_with_base(&self.helix_base);
+
+			// If our rate limit is empty, wait until it's refilled
+			if self.rate_limit_wait {
+				let time_until_reset = self
+					.rate_limits
+					.get(&oauth)
+					.filter(|rate_limit| rate_limit.is_empty())
+					.and_then(RateLimit::time_until_reset);
+				if let Some(time_until_reset) = time_until_reset {
+					tokio::time::delay_for(time_until_reset).await;
+				}
+			}
+
+			// Build the request and send it
+			let mut builder = self
+				.client
+				.request(Self::method(request.http_method()), url)
+				.bearer_auth(&oauth)
+				.header("Client-ID", client_id);
+			if let Some(body) = request.body() {
+				builder = builder.json(&body);
+			}
+			let response = builder.send().await;
 
-		// Translate the http method
-		let method = match request.http_method() {
+			// Then update our rate limit
+			let rate_limit = response.as_ref().ok().and_then(|response| RateLimit::from_headers(response.headers()));
+			if let Some(rate_limit) = rate_limit {
+				// Note: We also remove any rate limits that have already been refilled,
+				//       so we don't keep old tokens around.
+				self.rate_limits.retain(|_, old_rate_limit| old_rate_limit.time_until_reset().is_some());
+				self.rate_limits.insert(oauth, rate_limit);
+			}
+
+			// If our rate limit was empty, wait and retry, up to a limit.
+			// Note: We always wait at least a bit, so we don't spam Helix if the
+			//       rate limit was missing, or has already been refilled.
+			let is_rate_limited = matches!(&response, Ok(response) if response.status() == req::StatusCode::TOO_MANY_REQUESTS);
+			if self.rate_limit_wait && is_rate_limited && rate_limit_retries < MAX_RATE_LIMIT_RETRIES {
+				rate_limit_retries += 1;
+				let wait = rate_limit
+					.as_ref()
+					.and_then(RateLimit::time_until_reset)
+					.map_or(RATE_LIMIT_FALLBACK_WAIT, |time_until_reset| {
+						time_until_reset.max(RATE_LIMIT_FALLBACK_WAIT)
+					});
+				tokio::time::delay_for(wait).await;
+				continue;
+			}
+
+			// If it failed and we may retry it, wait and retry
+			attempt += 1;
+			let retry_delay = self
+				.retry_policy
+				.as_ref()
+				.and_then(|retry_policy| retry_policy.retry_delay(request.http_method(), attempt, &response));
+			if let Some(retry_delay) = retry_delay {
+				tokio::time::delay_for(retry_delay).await;
+				continue;
+			}
+			return response.map_err(RequestError::Send);
+		}
+	}
+
+	/// Performs a paginated request to Helix, following every page
+	///
+	/// Returns a stream with every item of every page, requesting
+	/// the next page only once all items of the current one have
+	/// been consumed.
+	///
+	/// The stream ends once there are no more pages, or once `limit`
+	/// items have been returned, if given.
+	/// If any error occurs, it is returned and the stream ends.
+	///
+	/// # Examples
+	/// ```no_run
+	/// # use twitch_helix::{Client, client::RequestError, request::search::channel::Request};
+	/// # use futures::TryStreamExt;
+	/// # async fn run(client: &mut Client, client_id: &str) -> Result<(), RequestError> {
+	/// let request = Request::new("my-channel").with_first(100);
+	/// let channels: Vec<_> = client
+	///   .request_helix_paginated(request, client_id, Some(500))
+	///   .try_collect()
+	///   .await?;
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn request_helix_paginated<'a, R, T>(
+		&'a mut self, request: R, client_id: &'a str, limit: Option<usize>,
+	) -> impl Stream<Item = Result<T, RequestError>> + 'a
+	where
+		R: PaginatedRequest<Response = Vec<T>> + Send + Sync + 'a,
+		T: 'a,
+	{
+		/// State of the stream
+		struct State<'a, R, T> {
+			/// Client
+			client: &'a mut Client,
+
+			/// Request for the next page
+			request: R,
+
+			/// Client id
+			client_id: &'a str,
+
+			/// Items left in the current page
+			items: VecDeque<T>,
+
+			/// Number of items left to return
+			remaining: Option<usize>,
+
+			/// If there are no more pages
+			finished: bool,
+		}
+
+		let state = State {
+			client: self,
+			request,
+			client_id,
+			items: VecDeque::new(),
+			remaining: limit,
+			finished: false,
+		};
+
+		stream::try_unfold(state, |mut state| async move {
+			loop {
+				// If we've returned all items requested, stop
+				if state.remaining == Some(0) {
+					return Ok(None);
+				}
+
+				// If we still have items in the current page, return the next one
+				if let Some(item) = state.items.pop_front() {
+					state.remaining = state.remaining.map(|remaining| remaining - 1);
+					return Ok(Some((item, state)));
+				}
+
+				// Else if there are no more pages, stop
+				if state.finished {
+					return Ok(None);
+				}
+
+				// Else request the next page
+				let response = state
+					.client
+					.request_helix(&state.request, state.client_id)
+					.await?
+					.into_result()
+					.map_err(RequestError::Helix)?;
+
+				// Then setup the request for the page after it, if any.
+				// Note: Twitch may return a cursor alongside an empty page, so we stop
+				//       on those too, as the following pages will also be empty.
+				match response.pagination.and_then(|pagination| pagination.cursor) {
+					Some(cursor) if !response.data.is_empty() => state.request.set_after(Some(cursor)),
+					_ => state.finished = true,
+				}
+				state.items.extend(response.data);
+			}
+		})
+	}
+
+	/// Parses a response
+	///
+	/// Some requests have no response, in which case the body is empty.
+	/// In order to parse these into `()`, the body is parsed as `null`.
+	async fn parse<T: for<'de> serde::Deserialize<'de>>(response: req::Response) -> Result<T, RequestError> {
+		let body = response.bytes().await.map_err(RequestError::Receive)?;
+		let body = if body.is_empty() { b"null" } else { body.as_ref() };
+		serde_json::from_slice(body).map_err(RequestError::Parse)
+	}
+
+	/// Translates an http method
+	fn method(method: HttpMethod) -> req::Method {
+		match method {
 			HttpMethod::Get => req::Method::GET,
 			HttpMethod::Post => req::Method::POST,
+			HttpMethod::Patch => req::Method::PATCH,
+			HttpMethod::Put => req::Method::PUT,
+			HttpMethod::Delete => req::Method::DELETE,
+		}
+	}
+}
+
+#[cfg(test)]
+mod test {
+	// Imports
+	use super::*;
+	use crate::request::games::top;
+	use chrono::TimeZone;
+	use futures::StreamExt;
+	use mock::{MockResponse, MockServer};
+	use std::convert::TryFrom;
+
+	/// Returns the paths of all requests a server received
+	fn paths(server: &MockServer) -> Vec<String> {
+		server.requests().into_iter().map(|request| request.path).collect()
+	}
+
+	/// First page, with a cursor to the second
+	const PAGE_1: &str = r#"{
+		"data": [
+			{ "box_art_url": "https://example.com/1-{width}x{height}.jpg", "id": "1", "name": "Game 1" },
+			{ "box_art_url": "https://example.com/2-{width}x{height}.jpg", "id": "2", "name": "Game 2" }
+		],
+		"pagination": { "cursor": "cursor-1" }
+	}"#;
+
+	/// Second page, with a cursor to the third
+	const PAGE_2: &str = r#"{
+		"data": [
+			{ "box_art_url": "https://example.com/3-{width}x{height}.jpg", "id": "3", "name": "Game 3" },
+			{ "box_art_url": "https://example.com/4-{width}x{height}.jpg", "id": "4", "name": "Game 4" }
+		],
+		"pagination": { "cursor": "cursor-2" }
+	}"#;
+
+	/// Empty page, still with a cursor
+	const EMPTY_PAGE: &str = r#"{ "data": [], "pagination": { "cursor": "cursor-3" } }"#;
+
+	#[tokio::test]
+	async fn paginated_stops_on_empty_page() {
+		let server = MockServer::start(vec![
+			MockResponse::new(200, PAGE_1),
+			MockResponse::new(200, PAGE_2),
+			MockResponse::new(200, EMPTY_PAGE),
+		])
+		.await;
+		let mut client = Client::new("my-token".to_owned()).with_helix_base(server.url("helix"));
+
+		let games: Vec<_> = client
+			.request_helix_paginated(top::Request::new(), "my-client-id", None)
+			.map(|game| game.expect("Unable to get game").id)
+			.collect()
+			.await;
+
+		assert_eq!(games, vec!["1", "2", "3", "4"]);
+		assert_eq!(
+			paths(&server),
+			vec!["/helix/games/top?", "/helix/games/top?after=cursor-1", "/helix/games/top?after=cursor-2"]
+		);
+	}
+
+	#[tokio::test]
+	async fn paginated_limit() {
+		let server = MockServer::start(vec![MockResponse::new(200, PAGE_1), MockResponse::new(200, PAGE_2)]).await;
+		let mut client = Client::new("my-token".to_owned()).with_helix_base(server.url("helix"));
+
+		let games: Vec<_> = client
+			.request_helix_paginated(top::Request::new(), "my-client-id", Some(3))
+			.map(|game| game.expect("Unable to get game").id)
+			.collect()
+			.await;
+
+		assert_eq!(games, vec!["1", "2", "3"]);
+		assert_eq!(server.requests().len(), 2);
+	}
+
+	#[tokio::test]
+	async fn paginated_error() {
+		let error = r#"{ "error": "Internal Server Error", "status": 500, "message": "Oops" }"#;
+		let server = MockServer::start(vec![MockResponse::new(200, PAGE_1), MockResponse::new(500, error)]).await;
+		let mut client = Client::new("my-token".to_owned()).with_helix_base(server.url("helix"));
+
+		let games: Vec<_> = client.request_helix_paginated(top::Request::new(), "my-client-id", None).collect().await;
+
+		assert_eq!(games.len(), 3);
+		assert!(games[..2].iter().all(Result::is_ok));
+		match &games[2] {
+			Err(RequestError::Helix(err)) => assert_eq!(err.status, 500),
+			res => panic!("Expected helix error, found {:?}", res.as_ref().map(|game| &game.id)),
+		}
+	}
+
+	#[tokio::test]
+	async fn poll_device_token_expired() {
+		let pending = r#"{ "status": 400, "message": "authorization_pending" }"#;
+		let slow_down = r#"{ "status": 400, "message": "slow_down" }"#;
+		let expired = r#"{ "status": 400, "message": "invalid device code" }"#;
+		let server = MockServer::start(vec![
+			MockResponse::new(400, pending),
+			MockResponse::new(400, slow_down),
+			MockResponse::new(400, expired),
+		])
+		.await;
+		let client = Client::new("my-token".to_owned()).with_oauth_base(server.url(""));
+
+		let device = device::Response {
+			device_code: "my-device-code".to_owned(),
+			expires_in: 1800,
+			interval: 0,
+			user_code: "ABCDEFGH".to_owned(),
+			verification_uri: "https://www.twitch.tv/activate".to_owned(),
 		};
+		let res = client
+			.poll_device_token("my-client-id", &device, Instant::now(), vec![Scope::ChannelReadEditors])
+			.await;
 
-		// Build the request and send it
-		let response = self
-			.client
-			.request(method, url)
-			.bearer_auth(&self.oauth)
-			.header("Client-ID", client_id)
-			.send()
+		assert!(matches!(res, Err(DeviceTokenError::Expired)), "Expected expired, found {:?}", res);
+		let requests = server.requests();
+		assert_eq!(requests.len(), 3);
+		assert!(requests.iter().all(|request| request.path == "/oauth2/token"));
+		assert!(requests[0].body.contains("device_code=my-device-code"));
+	}
+
+	#[tokio::test]
+	async fn poll_device_token_expired_before_polling() {
+		let server = MockServer::start(vec![]).await;
+		let client = Client::new("my-token".to_owned()).with_oauth_base(server.url(""));
+
+		let device = device::Response {
+			device_code: "my-device-code".to_owned(),
+			expires_in: 1,
+			interval: 0,
+			user_code: "ABCDEFGH".to_owned(),
+			verification_uri: "https://www.twitch.tv/activate".to_owned(),
+		};
+		let received_at = Instant::now() - Duration::from_secs(2);
+		let res = client.poll_device_token("my-client-id", &device, received_at, vec![]).await;
+
+		assert!(matches!(res, Err(DeviceTokenError::Expired)), "Expected expired, found {:?}", res);
+		assert!(server.requests().is_empty());
+	}
+
+	/// Unauthorized error
+	const UNAUTHORIZED: &str = r#"{ "error": "Unauthorized", "status": 401, "message": "Invalid OAuth token" }"#;
+
+	/// Refreshed token
+	const REFRESHED_TOKEN: &str = r#"{
+		"access_token": "new-token",
+		"refresh_token": "new-refresh-token",
+		"scope": ["channel:read:editors"],
+		"token_type": "bearer"
+	}"#;
+
+	#[tokio::test]
+	async fn refresh_on_unauthorized() {
+		let helix = MockServer::start(vec![MockResponse::new(401, UNAUTHORIZED), MockResponse::new(200, PAGE_1)]).await;
+		let oauth = MockServer::start(vec![MockResponse::new(200, REFRESHED_TOKEN)]).await;
+		let mut client = Client::new("old-token".to_owned())
+			.with_refresh("my-client-id".to_owned(), "my-client-secret".to_owned(), "old-refresh-token".to_owned())
+			.with_helix_base(helix.url("helix"))
+			.with_oauth_base(oauth.url(""));
+
+		let response = client
+			.request_helix(&top::Request::new(), "my-client-id")
 			.await
-			.map_err(RequestError::Send)?;
+			.expect("Unable to send request");
+		assert_eq!(response.status, req::StatusCode::OK);
 
-		// Then parse the response
-		let output = response.json().await.map_err(RequestError::Parse)?;
+		let requests = helix.requests();
+		assert_eq!(requests.len(), 2);
+		assert_eq!(requests[0].header("authorization"), Some("Bearer old-token"));
+		assert_eq!(requests[1].header("authorization"), Some("Bearer new-token"));
+		assert!(oauth.requests()[0].body.contains("refresh_token=old-refresh-token"));
+		assert_eq!(client.refresh_token(), Some("new-refresh-token"));
+		assert_eq!(client.scopes(), Some(&[Scope::ChannelReadEditors][..]));
+	}
+
+	#[tokio::test]
+	async fn refresh_on_unauthorized_once() {
+		let helix = MockServer::start(vec![MockResponse::new(401, UNAUTHORIZED), MockResponse::new(401, UNAUTHORIZED)]).await;
+		let oauth = MockServer::start(vec![MockResponse::new(200, REFRESHED_TOKEN)]).await;
+		let mut client = Client::new("old-token".to_owned())
+			.with_refresh("my-client-id".to_owned(), "my-client-secret".to_owned(), "old-refresh-token".to_owned())
+			.with_helix_base(helix.url("helix"))
+			.with_oauth_base(oauth.url(""));
+
+		let response = client
+			.request_helix(&top::Request::new(), "my-client-id")
+			.await
+			.expect("Unable to send request");
+		assert_eq!(response.status, req::StatusCode::UNAUTHORIZED);
+		assert_eq!(helix.requests().len(), 2);
+		assert_eq!(oauth.requests().len(), 1);
+	}
+
+	#[tokio::test]
+	async fn mint_app_token_before_expiry() {
+		// Note: Both tokens expire within the margin, so each request mints a new one.
+		let token = |oauth: &str| format!(r#"{{ "access_token": "{}", "expires_in": 30, "token_type": "bearer" }}"#, oauth);
+		let helix = MockServer::start(vec![MockResponse::new(200, PAGE_1), MockResponse::new(200, PAGE_2)]).await;
+		let oauth = MockServer::start(vec![
+			MockResponse::new(200, token("app-token-1")),
+			MockResponse::new(200, token("app-token-2")),
+		])
+		.await;
+		let mut client = Client::new_app("my-client-id".to_owned(), "my-client-secret".to_owned())
+			.with_helix_base(helix.url("helix"))
+			.with_oauth_base(oauth.url(""));
+
+		for _ in 0..2 {
+			client
+				.request_helix(&top::Request::new(), "my-client-id")
+				.await
+				.expect("Unable to send request");
+		}
+
+		let oauth_requests = oauth.requests();
+		assert_eq!(oauth_requests.len(), 2);
+		assert!(oauth_requests[0].body.contains("grant_type=client_credentials"));
+
+		let helix_requests = helix.requests();
+		assert_eq!(helix_requests[0].header("authorization"), Some("Bearer app-token-1"));
+		assert_eq!(helix_requests[1].header("authorization"), Some("Bearer app-token-2"));
+		assert_eq!(client.scopes(), Some(&[][..]));
+	}
+
+	/// Returns a response with the given rate limit headers
+	fn rate_limited(status: u16, body: &str, remaining: u64, reset: i64) -> MockResponse {
+		MockResponse::new(status, body)
+			.with_header("Ratelimit-Limit", 800)
+			.with_header("Ratelimit-Remaining", remaining)
+			.with_header("Ratelimit-Reset", reset)
+	}
+
+	#[tokio::test]
+	async fn rate_limit_retry() {
+		let now = chrono::Utc::now().timestamp();
+		let server = MockServer::start(vec![
+			rate_limited(429, r#"{ "error": "Too Many Requests", "status": 429, "message": "" }"#, 0, now),
+			rate_limited(200, PAGE_1, 799, now + 60),
+		])
+		.await;
+		let mut client = Client::new("my-oauth".to_owned())
+			.with_helix_base(server.url("helix"))
+			.with_rate_limit_wait(true);
+
+		client
+			.request_helix(&top::Request::new(), "my-client-id")
+			.await
+			.expect("Unable to send request");
+
+		assert_eq!(server.requests().len(), 2);
+		assert_eq!(
+			client.rate_limit(),
+			Some(RateLimit {
+				limit: 800,
+				remaining: 799,
+				reset: chrono::Utc.timestamp_opt(now + 60, 0).single().expect("Invalid timestamp"),
+			})
+		);
+	}
+
+	#[tokio::test]
+	async fn rate_limit_wait_before_sending() {
+		// Note: The reset only has second precision, so we may wait anywhere from 1 to 2 seconds.
+		let reset = chrono::Utc::now().timestamp() + 2;
+		let server = MockServer::start(vec![rate_limited(200, PAGE_1, 0, reset), rate_limited(200, PAGE_2, 799, reset + 60)]).await;
+		let mut client = Client::new("my-oauth".to_owned())
+			.with_helix_base(server.url("helix"))
+			.with_rate_limit_wait(true);
 
-		Ok(output)
+		client
+			.request_helix(&top::Request::new(), "my-client-id")
+			.await
+			.expect("Unable to send request");
+		assert!(client.rate_limit().expect("Missing rate limit").is_empty());
+
+		let start = Instant::now();
+		client
+			.request_helix(&top::Request::new(), "my-client-id")
+			.await
+			.expect("Unable to send request");
+		assert!(start.elapsed() >= Duration::from_secs(1));
+		assert_eq!(server.requests().len(), 2);
+		assert_eq!(client.rate_limit().map(|rate_limit| rate_limit.remaining), Some(799));
+	}
+
+	#[tokio::test]
+	async fn rate_limit_max_retries() {
+		// Note: Without any headers, each retry waits the fallback wait.
+		let too_many_requests = MockResponse::new(429, r#"{ "error": "Too Many Requests", "status": 429, "message": "" }"#);
+		let max_retries = usize::try_from(MAX_RATE_LIMIT_RETRIES).expect("Max retries didn't fit");
+		let responses = vec![too_many_requests; max_retries + 2];
+		let server = MockServer::start(responses).await;
+		let mut client = Client::new("my-oauth".to_owned())
+			.with_helix_base(server.url("helix"))
+			.with_rate_limit_wait(true);
+
+		let response = client
+			.request_helix(&top::Request::new(), "my-client-id")
+			.await
+			.expect("Unable to send request");
+		assert_eq!(response.status, req::StatusCode::TOO_MANY_REQUESTS);
+		assert_eq!(server.requests().len(), max_retries + 1);
 	}
 }
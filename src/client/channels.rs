@@ -0,0 +1,159 @@
+//! Channel helpers
+
+// Imports
+use super::{Client, RequestError};
+use crate::request::channels::info::{self, Channel};
+use futures::{stream, StreamExt};
+use std::collections::{HashMap, HashSet};
+
+/// Maximum number of chunks requested at once, when requesting concurrently
+const MAX_CONCURRENT_REQUESTS: usize = 4;
+
+/// Information about several channels
+///
+/// Returned by [`Client::request_channels_info`].
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct ChannelsInfo {
+	/// All channels returned, by broadcaster id
+	pub channels: HashMap<String, Channel>,
+
+	/// All broadcaster ids requested that weren't returned
+	pub missing: Vec<String>,
+}
+
+impl Client {
+	/// Requests information about any number of channels
+	///
+	/// The broadcaster ids are requested in chunks of up to [`info::MAX_BROADCASTER_IDS`],
+	/// either one after the other, or concurrently, if `concurrent` is set, with up to
+	/// 4 chunks being requested at once.
+	///
+	/// Concurrent chunks are requested with copies of this client, whose rate limits are
+	/// then kept by this client as each chunk finishes. The token is gotten beforehand and
+	/// shared by every copy, so any new token a copy gets is not kept.
+	pub async fn request_channels_info<I>(&mut self, broadcaster_ids: I, client_id: &str, concurrent: bool) -> Result<ChannelsInfo, RequestError>
+	where
+		I: IntoIterator,
+		I::Item: Into<String>,
+	{
+		// Remove any duplicate ids, so we don't request them twice
+		let mut seen = HashSet::new();
+		let broadcaster_ids: Vec<String> = broadcaster_ids
+			.into_iter()
+			.map(Into::into)
+			.filter(|broadcaster_id| seen.insert(broadcaster_id.clone()))
+			.collect();
+		let requests: Vec<_> = broadcaster_ids.chunks(info::MAX_BROADCASTER_IDS).map(info::Request::from_ids).collect();
+
+		// Request every chunk
+		let mut channels = HashMap::new();
+		if concurrent {
+			// Note: We get the token beforehand, so each copy doesn't get it's own.
+			self.oauth().await?;
+			let clients: Vec<Self> = requests.iter().map(|_| self.clone()).collect();
+			let mut responses = stream::iter(clients.into_iter().zip(&requests))
+				.map(|(mut client, request)| async move {
+					let response = client.request_helix(request, client_id).await;
+					(client, response)
+				})
+				.buffer_unordered(MAX_CONCURRENT_REQUESTS);
+
+			while let Some((client, response)) = responses.next().await {
+				self.merge_rate_limits(client);
+				let data = response?.into_result().map_err(RequestError::Helix)?.data;
+				channels.extend(data.into_iter().map(|channel| (channel.broadcaster_id.clone(), channel)));
+			}
+		} else {
+			for request in &requests {
+				let data = self
+					.request_helix(request, client_id)
+					.await?
+					.into_result()
+					.map_err(RequestError::Helix)?
+					.data;
+				channels.extend(data.into_iter().map(|channel| (channel.broadcaster_id.clone(), channel)));
+			}
+		}
+
+		// Then check which weren't returned
+		let missing = broadcaster_ids
+			.into_iter()
+			.filter(|broadcaster_id| !channels.contains_key(broadcaster_id))
+			.collect();
+
+		Ok(ChannelsInfo { channels, missing })
+	}
+
+	/// Keeps the rate limits of a copy of this client
+	fn merge_rate_limits(&mut self, client: Self) {
+		self.rate_limits.extend(client.rate_limits);
+	}
+}
+
+#[cfg(test)]
+mod test {
+	// Imports
+	use super::*;
+	use crate::client::mock::{MockResponse, MockServer};
+
+	/// Returns a response with a channel for each id
+	fn channels_response(ids: impl Iterator<Item = usize>) -> String {
+		let channels: Vec<String> = ids
+			.map(|id| {
+				format!(
+					r#"{{
+						"broadcaster_id": "{0}", "broadcaster_login": "channel{0}", "broadcaster_name": "Channel{0}",
+						"broadcaster_language": "en", "game_id": "", "game_name": "", "title": "", "delay": 0,
+						"tags": [], "content_classification_labels": [], "is_branded_content": false
+					}}"#,
+					id
+				)
+			})
+			.collect();
+		format!(r#"{{ "data": [{}] }}"#, channels.join(","))
+	}
+
+	/// Requests 151 channels, with a duplicate, of which the last isn't returned
+	async fn request_channels_info(concurrent: bool) {
+		// Note: As concurrent chunks may be requested in any order, either response
+		//       may be returned for either chunk, so we only check the overall result.
+		let server = MockServer::start(vec![
+			MockResponse::new(200, channels_response(0..100)),
+			MockResponse::new(200, channels_response(100..150)),
+		])
+		.await;
+		let mut client = Client::new("my-token".to_owned()).with_helix_base(server.url("helix"));
+
+		let broadcaster_ids = (0..=150).map(|id| id.to_string()).chain(std::iter::once("7".to_owned()));
+		let info = client
+			.request_channels_info(broadcaster_ids, "my-client-id", concurrent)
+			.await
+			.expect("Unable to request channels");
+
+		let mut ids_per_request: Vec<usize> = server
+			.requests()
+			.iter()
+			.map(|request| request.path.matches("broadcaster_id=").count())
+			.collect();
+		ids_per_request.sort_unstable();
+		assert_eq!(ids_per_request, vec![51, 100]);
+
+		assert_eq!(info.channels.len(), 150);
+		assert!(info
+			.channels
+			.iter()
+			.all(|(broadcaster_id, channel)| *broadcaster_id == channel.broadcaster_id));
+		assert_eq!(info.channels["7"].broadcaster_login, "channel7");
+		assert_eq!(info.missing, vec!["150".to_owned()]);
+	}
+
+	#[tokio::test]
+	async fn request_channels_info_sequential() {
+		request_channels_info(false).await;
+	}
+
+	#[tokio::test]
+	async fn request_channels_info_concurrent() {
+		request_channels_info(true).await;
+	}
+}
@@ -0,0 +1,47 @@
+//! Commercial helpers
+
+// Imports
+use super::{Client, RequestError};
+use crate::request::channels::commercial::{self, Length};
+use std::time::{Duration, Instant};
+
+/// A started commercial
+///
+/// Returned by [`Client::start_commercial`].
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Commercial {
+	/// Length of the commercial
+	///
+	/// Twitch may shorten the commercial from the length requested.
+	pub length: Duration,
+
+	/// Message explaining why the length was changed, if it was
+	pub message: Option<String>,
+
+	/// When the next commercial may be started, if it must wait
+	pub retry_at: Option<Instant>,
+}
+
+impl Client {
+	/// Starts a commercial on a channel
+	///
+	/// If twitch says the next commercial must wait, returns
+	/// when it may be started, counted from after receiving the response.
+	pub async fn start_commercial(&mut self, broadcaster_id: &str, length: Length, client_id: &str) -> Result<Commercial, RequestError> {
+		let request = commercial::Request::new(broadcaster_id, length);
+		let [response] = self
+			.request_helix(&request, client_id)
+			.await?
+			.into_result()
+			.map_err(RequestError::Helix)?
+			.data;
+
+		Ok(Commercial {
+			length: response.length,
+			message: Some(response.message).filter(|message| !message.is_empty()),
+			retry_at: Some(response.retry_after)
+				.filter(|retry_after| !retry_after.is_zero())
+				.map(|retry_after| Instant::now() + retry_after),
+		})
+	}
+}
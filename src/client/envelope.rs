@@ -0,0 +1,114 @@
+//! Helix response envelope
+
+// Imports
+use super::RateLimit;
+use crate::{
+	response::{ResponseData, ResponseError},
+	HelixResponse,
+};
+use reqwest as req;
+
+/// Helix response envelope
+///
+/// Contains the parsed [`HelixResponse`], alongside the http status
+/// and headers it was received with.
+#[derive(Clone, Debug)]
+pub struct HelixEnvelope<T, E = ()> {
+	/// Http status
+	pub status: req::StatusCode,
+
+	/// Headers
+	pub headers: req::header::HeaderMap,
+
+	/// Rate limit, if returned
+	pub rate_limit: Option<RateLimit>,
+
+	/// Parsed response
+	pub response: HelixResponse<T, E>,
+}
+
+impl<T, E> HelixEnvelope<T, E> {
+	/// Turns this envelope into the underlying response
+	pub fn into_response(self) -> HelixResponse<T, E> {
+		self.response
+	}
+
+	/// Turns this envelope into a `Result`
+	pub fn into_result(self) -> Result<ResponseData<T, E>, ResponseError> {
+		self.response.into_result()
+	}
+}
+
+/// Parses the body of a helix response given it's status
+///
+/// Successful responses without a body, such as `204 No Content`,
+/// have their data parsed from `null`, so they may be parsed into `()`.
+///
+/// Unsuccessful responses whose body isn't an error are turned
+/// into one from the status and body.
+pub(crate) fn parse_body<T, E>(status: req::StatusCode, body: &[u8]) -> Result<HelixResponse<T, E>, serde_json::Error>
+where
+	T: for<'de> serde::Deserialize<'de>,
+	E: for<'de> serde::Deserialize<'de>,
+{
+	// If we failed, parse the error, or build it ourselves
+	if !status.is_success() {
+		let err = serde_json::from_slice(body).unwrap_or_else(|_| ResponseError {
+			error: status.canonical_reason().map(String::from),
+			status: usize::from(status.as_u16()),
+			message: String::from_utf8_lossy(body).into_owned(),
+		});
+		return Ok(HelixResponse::Err(err));
+	}
+
+	// Else parse the data, if we have any
+	let body = if body.is_empty() { br#"{"data": null}"# } else { body };
+	serde_json::from_slice(body).map(HelixResponse::Ok)
+}
+
+#[cfg(test)]
+mod test {
+	// Imports
+	use super::*;
+
+	#[test]
+	fn parse_ok() {
+		let body = br#"{"data": [1, 2, 3], "pagination": {"cursor": "abc"}}"#;
+		let response: HelixResponse<Vec<u32>> = parse_body(req::StatusCode::OK, body).expect("Unable to parse response");
+		let data = response.into_result().expect("Response was an error");
+		assert_eq!(data.data, vec![1, 2, 3]);
+		assert_eq!(data.pagination.and_then(|pagination| pagination.cursor).as_deref(), Some("abc"));
+	}
+
+	#[test]
+	fn parse_no_content() {
+		let response: HelixResponse<()> = parse_body(req::StatusCode::NO_CONTENT, b"").expect("Unable to parse response");
+		assert_eq!(
+			response,
+			HelixResponse::Ok(ResponseData {
+				data: (),
+				pagination: None,
+				extra: ()
+			})
+		);
+	}
+
+	#[test]
+	fn parse_err() {
+		let body = br#"{"error": "Unauthorized", "status": 401, "message": "Invalid OAuth token"}"#;
+		let response: HelixResponse<()> = parse_body(req::StatusCode::UNAUTHORIZED, body).expect("Unable to parse response");
+		let err = response.into_result().expect_err("Response wasn't an error");
+		assert_eq!(err.error.as_deref(), Some("Unauthorized"));
+		assert_eq!(err.status, 401);
+		assert_eq!(err.message, "Invalid OAuth token");
+	}
+
+	#[test]
+	fn parse_err_no_body() {
+		let response: HelixResponse<()> = parse_body(req::StatusCode::BAD_GATEWAY, b"Bad gateway").expect("Unable to parse response");
+		let err = response.into_result().expect_err("Response wasn't an error");
+		assert_eq!(err.error.as_deref(), Some("Bad Gateway"));
+		assert_eq!(err.status, 502);
+		assert_eq!(err.message, "Bad gateway");
+	}
+}
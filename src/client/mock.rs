@@ -0,0 +1,157 @@
+//! Mock server, for testing the client
+
+// Imports
+use std::{
+	net::SocketAddr,
+	sync::{Arc, Mutex},
+};
+use tokio::{
+	io::{AsyncReadExt, AsyncWriteExt},
+	net::TcpListener,
+};
+
+/// A response for the mock server to return
+#[derive(Clone, Debug)]
+pub struct MockResponse {
+	/// Http status
+	status: u16,
+
+	/// Extra headers
+	headers: Vec<(String, String)>,
+
+	/// Body
+	body: String,
+}
+
+impl MockResponse {
+	/// Creates a new response given it's status and body
+	pub fn new(status: u16, body: impl Into<String>) -> Self {
+		Self {
+			status,
+			headers: vec![],
+			body: body.into(),
+		}
+	}
+
+	/// Adds a header to this response
+	pub fn with_header(mut self, name: &str, value: impl ToString) -> Self {
+		self.headers.push((name.to_owned(), value.to_string()));
+		self
+	}
+}
+
+/// A request received by the mock server
+#[derive(Clone, Debug)]
+pub struct MockRequest {
+	/// Path and query
+	pub path: String,
+
+	/// Headers, with lowercase names
+	pub headers: Vec<(String, String)>,
+
+	/// Body
+	pub body: String,
+}
+
+impl MockRequest {
+	/// Returns the value of a header, given it's lowercase name
+	pub fn header(&self, name: &str) -> Option<&str> {
+		self.headers
+			.iter()
+			.find(|(header_name, _)| header_name == name)
+			.map(|(_, value)| value.as_str())
+	}
+}
+
+/// A mock server
+///
+/// Returns each response given in order, one per connection, and records
+/// every request received.
+pub struct MockServer {
+	/// Address
+	addr: SocketAddr,
+
+	/// All requests received
+	requests: Arc<Mutex<Vec<MockRequest>>>,
+}
+
+impl MockServer {
+	/// Starts a new mock server that returns each response in order
+	pub async fn start(responses: Vec<MockResponse>) -> Self {
+		let mut listener = TcpListener::bind(SocketAddr::from(([127, 0, 0, 1], 0)))
+			.await
+			.expect("Unable to bind listener");
+		let addr = listener.local_addr().expect("Unable to get listener address");
+		let requests = Arc::new(Mutex::new(vec![]));
+
+		let server_requests = Arc::clone(&requests);
+		tokio::spawn(async move {
+			for response in responses {
+				let (mut stream, _) = listener.accept().await.expect("Unable to accept connection");
+
+				// Read the request line and headers
+				let mut request = vec![];
+				let mut buffer = [0; 1024];
+				let headers_len = loop {
+					if let Some(pos) = request.windows(4).position(|window| window == b"\r\n\r\n") {
+						break pos + 4;
+					}
+					let len = stream.read(&mut buffer).await.expect("Unable to read request");
+					assert_ne!(len, 0, "Connection closed before the request was read");
+					request.extend_from_slice(&buffer[..len]);
+				};
+				let head = String::from_utf8(request[..headers_len].to_vec()).expect("Request wasn't utf-8");
+				let mut lines = head.lines();
+				let path = lines
+					.next()
+					.and_then(|line| line.split(' ').nth(1))
+					.expect("Request had no path")
+					.to_owned();
+				let headers: Vec<(String, String)> = lines
+					.filter_map(|line| {
+						let mut parts = line.splitn(2, ": ");
+						Some((parts.next()?.to_ascii_lowercase(), parts.next()?.to_owned()))
+					})
+					.collect();
+
+				// Then read the body, if any
+				let body_len: usize = headers
+					.iter()
+					.find(|(name, _)| name == "content-length")
+					.map_or(0, |(_, value)| value.parse().expect("Invalid content length"));
+				while request.len() < headers_len + body_len {
+					let len = stream.read(&mut buffer).await.expect("Unable to read request");
+					assert_ne!(len, 0, "Connection closed before the request was read");
+					request.extend_from_slice(&buffer[..len]);
+				}
+				let body = String::from_utf8(request[headers_len..].to_vec()).expect("Request wasn't utf-8");
+				server_requests.lock().expect("Poisoned").push(MockRequest { path, headers, body });
+
+				// Then send the response and close the connection
+				let mut head = format!(
+					"HTTP/1.1 {} Mock\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n",
+					response.status,
+					response.body.len()
+				);
+				for (name, value) in &response.headers {
+					head.push_str(&format!("{}: {}\r\n", name, value));
+				}
+				head.push_str("\r\n");
+				stream.write_all(head.as_bytes()).await.expect("Unable to write response");
+				stream.write_all(response.body.as_bytes()).await.expect("Unable to write response");
+			}
+		});
+
+		Self { addr, requests }
+	}
+
+	/// Returns the url of a path in this server
+	pub fn url(&self, path: &str) -> url::Url {
+		url::Url::parse(&format!("http://{}/{}", self.addr, path)).expect("Unable to parse mock url")
+	}
+
+	/// Returns all requests received so far
+	pub fn requests(&self) -> Vec<MockRequest> {
+		self.requests.lock().expect("Poisoned").clone()
+	}
+}
@@ -0,0 +1,86 @@
+//! Helix rate limits
+
+// Imports
+use chrono::{DateTime, TimeZone, Utc};
+use reqwest as req;
+use std::{str::FromStr, time::Duration};
+
+/// Helix rate limit bucket
+///
+/// Helix limits the number of requests each token may make,
+/// using a bucket of points that refills over time. The
+/// state of the bucket is returned with every response.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub struct RateLimit {
+	/// Maximum number of points in the bucket
+	pub limit: u64,
+
+	/// Number of points left in the bucket
+	pub remaining: u64,
+
+	/// When the bucket will be refilled
+	pub reset: DateTime<Utc>,
+}
+
+impl RateLimit {
+	/// Parses a rate limit from the `Ratelimit-*` headers of a response
+	///
+	/// Returns `None` if any of them are missing or invalid.
+	pub(crate) fn from_headers(headers: &req::header::HeaderMap) -> Option<Self> {
+		/// Parses a header as a number
+		fn parse_header<T: FromStr>(headers: &req::header::HeaderMap, name: &str) -> Option<T> {
+			headers.get(name)?.to_str().ok()?.parse().ok()
+		}
+
+		Some(Self {
+			limit: parse_header(headers, "Ratelimit-Limit")?,
+			remaining: parse_header(headers, "Ratelimit-Remaining")?,
+			reset: Utc.timestamp_opt(parse_header(headers, "Ratelimit-Reset")?, 0).single()?,
+		})
+	}
+
+	/// Returns if the bucket is empty
+	#[must_use]
+	pub const fn is_empty(&self) -> bool {
+		self.remaining == 0
+	}
+
+	/// Returns how long until the bucket is refilled
+	///
+	/// If it has already been refilled, returns `None`.
+	#[must_use]
+	pub fn time_until_reset(&self) -> Option<Duration> {
+		(self.reset - Utc::now()).to_std().ok()
+	}
+}
+
+#[cfg(test)]
+mod test {
+	// Imports
+	use super::*;
+	use req::header::{HeaderMap, HeaderValue};
+
+	#[test]
+	fn from_headers() {
+		let mut headers = HeaderMap::new();
+		headers.insert("Ratelimit-Limit", HeaderValue::from_static("800"));
+		headers.insert("Ratelimit-Remaining", HeaderValue::from_static("799"));
+		headers.insert("Ratelimit-Reset", HeaderValue::from_static("1595515773"));
+
+		let rate_limit = RateLimit::from_headers(&headers).expect("Unable to parse rate limit");
+		assert_eq!(rate_limit.limit, 800);
+		assert_eq!(rate_limit.remaining, 799);
+		assert_eq!(rate_limit.reset, Utc.timestamp_opt(1_595_515_773, 0).single().expect("Invalid timestamp"));
+		assert!(!rate_limit.is_empty());
+		assert_eq!(rate_limit.time_until_reset(), None);
+	}
+
+	#[test]
+	fn from_headers_missing() {
+		let mut headers = HeaderMap::new();
+		headers.insert("Ratelimit-Limit", HeaderValue::from_static("800"));
+		headers.insert("Ratelimit-Remaining", HeaderValue::from_static("799"));
+
+		assert_eq!(RateLimit::from_headers(&headers), None);
+	}
+}
@@ -0,0 +1,299 @@
+//! Retry policy
+
+// Imports
+use crate::HttpMethod;
+use rand::Rng;
+use reqwest as req;
+use std::time::Duration;
+
+/// Retry policy for transient failures
+///
+/// Describes which failed requests to retry, and how long to
+/// wait before each retry. The wait doubles on each retry, starting
+/// at the base delay, up to the maximum delay.
+///
+/// Requests that aren't idempotent, such as starting a commercial, are,
+/// by default, only retried if they couldn't be sent, as otherwise they might
+/// have been processed by twitch.
+///
+/// # Examples
+/// ```
+/// # use twitch_helix::client::{RetryPolicy, SendErrorKind};
+/// # use std::time::Duration;
+/// let policy = RetryPolicy::new()
+///   .with_max_attempts(5)
+///   .with_base_delay(Duration::from_millis(250))
+///   .with_jitter(true)
+///   .with_statuses(vec![502, 503])
+///   .with_send_error_kinds(vec![SendErrorKind::Connect]);
+///
+/// assert_eq!(policy.max_attempts(), 5);
+/// ```
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct RetryPolicy {
+	/// Maximum number of attempts, including the first one
+	max_attempts: u32,
+
+	/// Delay before the first retry
+	base_delay: Duration,
+
+	/// Maximum delay before each retry
+	max_delay: Duration,
+
+	/// If the delay should be randomized
+	jitter: bool,
+
+	/// Http statuses to retry
+	statuses: Vec<u16>,
+
+	/// Kinds of send errors to retry
+	send_error_kinds: Vec<SendErrorKind>,
+
+	/// If requests that aren't idempotent should be retried
+	retry_non_idempotent: bool,
+}
+
+/// A kind of error when sending a request
+#[derive(PartialEq, Eq, Clone, Copy, Hash, Debug)]
+pub enum SendErrorKind {
+	/// Unable to connect
+	///
+	/// The request was never sent.
+	Connect,
+
+	/// Timed out
+	Timeout,
+
+	/// Any other error
+	Other,
+}
+
+impl SendErrorKind {
+	/// Returns the kind of a send error
+	fn of(err: &req::Error) -> Self {
+		if err.is_connect() {
+			Self::Connect
+		} else if err.is_timeout() {
+			Self::Timeout
+		} else {
+			Self::Other
+		}
+	}
+}
+
+impl RetryPolicy {
+	/// Creates a new retry policy
+	///
+	/// By default, requests are attempted up to 3 times, starting with a
+	/// delay of 500ms, up to 10s, with jitter, retrying on `500`, `502`, `503`
+	/// and `504` statuses, as well as on connection errors and timeouts.
+	#[must_use]
+	pub fn new() -> Self {
+		Self {
+			max_attempts: 3,
+			base_delay: Duration::from_millis(500),
+			max_delay: Duration::from_secs(10),
+			jitter: true,
+			statuses: vec![500, 502, 503, 504],
+			send_error_kinds: vec![SendErrorKind::Connect, SendErrorKind::Timeout],
+			retry_non_idempotent: false,
+		}
+	}
+
+	/// Sets the maximum number of attempts, including the first one
+	#[must_use]
+	pub fn with_max_attempts(self, max_attempts: u32) -> Self {
+		Self { max_attempts, ..self }
+	}
+
+	/// Sets the delay before the first retry
+	#[must_use]
+	pub fn with_base_delay(self, base_delay: Duration) -> Self {
+		Self { base_delay, ..self }
+	}
+
+	/// Sets the maximum delay before each retry
+	#[must_use]
+	pub fn with_max_delay(self, max_delay: Duration) -> Self {
+		Self { max_delay, ..self }
+	}
+
+	/// Sets if the delay should be randomized
+	///
+	/// When set, each delay is randomized to between half and all of it.
+	#[must_use]
+	pub fn with_jitter(self, jitter: bool) -> Self {
+		Self { jitter, ..self }
+	}
+
+	/// Sets the http statuses to retry
+	#[must_use]
+	pub fn with_statuses(self, statuses: Vec<u16>) -> Self {
+		Self { statuses, ..self }
+	}
+
+	/// Sets the kinds of send errors to retry
+	#[must_use]
+	pub fn with_send_error_kinds(self, send_error_kinds: Vec<SendErrorKind>) -> Self {
+		Self { send_error_kinds, ..self }
+	}
+
+	/// Sets if requests that aren't idempotent should be retried
+	///
+	/// When not set, these are only retried on connection errors.
+	#[must_use]
+	pub fn with_retry_non_idempotent(self, retry_non_idempotent: bool) -> Self {
+		Self {
+			retry_non_idempotent,
+			..self
+		}
+	}
+
+	/// Returns the maximum number of attempts, including the first one
+	#[must_use]
+	pub const fn max_attempts(&self) -> u32 {
+		self.max_attempts
+	}
+
+	/// Returns how long to wait before retrying a request, if it should be retried
+	///
+	/// `attempt` is the number of attempts made so far, including the one that
+	/// resulted in `result`.
+	pub(crate) fn retry_delay(&self, method: HttpMethod, attempt: u32, result: &Result<req::Response, req::Error>) -> Option<Duration> {
+		// If we're out of attempts, don't retry
+		if attempt >= self.max_attempts {
+			return None;
+		}
+
+		// Else check if we can retry this failure
+		let should_retry = match result {
+			Ok(response) => (method.is_idempotent() || self.retry_non_idempotent) && self.statuses.contains(&response.status().as_u16()),
+			Err(err) => {
+				let kind = SendErrorKind::of(err);
+				(method.is_idempotent() || self.retry_non_idempotent || kind == SendErrorKind::Connect) && self.send_error_kinds.contains(&kind)
+			}
+		};
+		if !should_retry {
+			return None;
+		}
+
+		// Then randomize the delay, if we have jitter
+		let delay = self.delay(attempt);
+		if self.jitter {
+			Some(rand::thread_rng().gen_range(delay / 2..=delay))
+		} else {
+			Some(delay)
+		}
+	}
+
+	/// Returns the delay, without jitter, before retrying after `attempt` attempts
+	///
+	/// The delay doubles for each attempt, starting at the base delay.
+	fn delay(&self, attempt: u32) -> Duration {
+		attempt
+			.checked_sub(1)
+			.and_then(|exponent| 2_u32.checked_pow(exponent))
+			.and_then(|factor| self.base_delay.checked_mul(factor))
+			.map_or(self.max_delay, |delay| delay.min(self.max_delay))
+	}
+}
+
+impl Default for RetryPolicy {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+#[cfg(test)]
+mod test {
+	// Imports
+	use super::*;
+
+	#[test]
+	fn delay_doubles() {
+		let policy = RetryPolicy::new()
+			.with_base_delay(Duration::from_millis(100))
+			.with_max_delay(Duration::from_millis(500));
+
+		assert_eq!(policy.delay(1), Duration::from_millis(100));
+		assert_eq!(policy.delay(2), Duration::from_millis(200));
+		assert_eq!(policy.delay(3), Duration::from_millis(400));
+		assert_eq!(policy.delay(4), Duration::from_millis(500));
+		assert_eq!(policy.delay(100), Duration::from_millis(500));
+	}
+
+	/// Returns a response with a status
+	fn response(status: u16) -> Result<req::Response, req::Error> {
+		let response = http::Response::builder().status(status).body("").expect("Unable to build response");
+		Ok(req::Response::from(response))
+	}
+
+	/// Returns the error of a request that couldn't connect
+	async fn connect_error() -> Result<req::Response, req::Error> {
+		// Note: We bind a listener and drop it, so nothing is listening on it's port.
+		let listener = std::net::TcpListener::bind("127.0.0.1:0").expect("Unable to bind listener");
+		let addr = listener.local_addr().expect("Unable to get listener address");
+		drop(listener);
+
+		req::Client::new().get(&format!("http://{}", addr)).send().await
+	}
+
+	/// Returns the error of a request that timed out
+	async fn timeout_error() -> Result<req::Response, req::Error> {
+		// Note: We keep the listener alive, but never accept, so the request never gets a response.
+		let listener = std::net::TcpListener::bind("127.0.0.1:0").expect("Unable to bind listener");
+		let addr = listener.local_addr().expect("Unable to get listener address");
+
+		let client = req::Client::builder()
+			.timeout(Duration::from_millis(100))
+			.build()
+			.expect("Unable to build client");
+		let result = client.get(&format!("http://{}", addr)).send().await;
+		drop(listener);
+		result
+	}
+
+	/// Returns a policy without jitter
+	fn policy() -> RetryPolicy {
+		RetryPolicy::new().with_base_delay(Duration::from_millis(100)).with_jitter(false)
+	}
+
+	#[test]
+	fn retry_status_idempotent_only() {
+		let policy = policy();
+		assert_eq!(policy.retry_delay(HttpMethod::Get, 1, &response(503)), Some(Duration::from_millis(100)));
+		assert_eq!(policy.retry_delay(HttpMethod::Post, 1, &response(503)), None);
+		assert_eq!(policy.retry_delay(HttpMethod::Get, 1, &response(404)), None);
+
+		let policy = policy.with_retry_non_idempotent(true);
+		assert_eq!(policy.retry_delay(HttpMethod::Post, 1, &response(503)), Some(Duration::from_millis(100)));
+	}
+
+	#[test]
+	fn retry_max_attempts() {
+		let policy = policy().with_max_attempts(3);
+		assert_eq!(policy.retry_delay(HttpMethod::Get, 2, &response(503)), Some(Duration::from_millis(200)));
+		assert_eq!(policy.retry_delay(HttpMethod::Get, 3, &response(503)), None);
+	}
+
+	#[tokio::test]
+	async fn retry_connect_error() {
+		let policy = policy();
+		let result = connect_error().await;
+		assert_eq!(SendErrorKind::of(result.as_ref().expect_err("Request connected")), SendErrorKind::Connect);
+		assert_eq!(policy.retry_delay(HttpMethod::Get, 1, &result), Some(Duration::from_millis(100)));
+		assert_eq!(policy.retry_delay(HttpMethod::Post, 1, &result), Some(Duration::from_millis(100)));
+	}
+
+	#[tokio::test]
+	async fn retry_timeout_idempotent_only() {
+		let policy = policy();
+		let result = timeout_error().await;
+		assert_eq!(
+			SendErrorKind::of(result.as_ref().expect_err("Request didn't time out")),
+			SendErrorKind::Timeout
+		);
+		assert_eq!(policy.retry_delay(HttpMethod::Get, 1, &result), Some(Duration::from_millis(100)));
+		assert_eq!(policy.retry_delay(HttpMethod::Post, 1, &result), None);
+	}
+}
@@ -45,5 +45,5 @@ mod util;
 // Exports
 #[cfg(feature = "client")]
 pub use client::Client;
-pub use request::{HelixRequest, HttpMethod, OAuthRequest};
+pub use request::{HelixRequest, HttpMethod, OAuthRequest, PaginatedRequest};
 pub use response::{HelixResponse, OAuthResponse};
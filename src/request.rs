@@ -2,11 +2,17 @@
 
 // Modules
 pub mod analytics;
+pub mod bits;
+pub mod channel_points;
 pub mod channels;
 pub mod games;
 pub mod oauth;
 pub mod search;
 
+// Imports
+use crate::{helix_url, oauth_url};
+use oauth::Scope;
+
 // Exports
 #[deprecated = "Use `channels`"]
 pub use channels as channel;
@@ -19,6 +25,29 @@ pub enum HttpMethod {
 
 	/// Post
 	Post,
+
+	/// Patch
+	Patch,
+
+	/// Put
+	Put,
+
+	/// Delete
+	Delete,
+}
+
+impl HttpMethod {
+	/// Returns if this method is idempotent
+	///
+	/// Idempotent requests may be safely retried, as making
+	/// them several times has the same effect as once.
+	#[must_use]
+	pub const fn is_idempotent(self) -> bool {
+		match self {
+			Self::Get | Self::Put | Self::Delete => true,
+			Self::Post | Self::Patch => false,
+		}
+	}
 }
 
 /// A Helix request
@@ -28,17 +57,77 @@ pub enum HttpMethod {
 /// A [`HelixResponse`](crate::HelixResponse) is returned, that includes
 /// this response type inside it's `Ok` variant, in the `data` field.
 ///
+/// Some responses also contain extra fields alongside the `data` field,
+/// which are returned in the `extra` field, as the request's extra type.
+/// Requests without any extra fields use `()`.
+///
 /// A helix request must simply return it's url and what http method to
-/// use to make the request.
+/// use to make the request. Requests that send data in their body, instead
+/// of the url, must also return their body, as the request's body type.
+/// Requests without a body use `()`.
+///
+/// The url is built on top of a base helix url, so that requests may
+/// be made to somewhere other than twitch, such as a local mock server.
 pub trait HelixRequest {
 	/// Response type
 	type Response: for<'de> serde::Deserialize<'de>;
 
-	/// Returns this request's url
-	fn url(&self) -> url::Url;
+	/// Extra response fields type
+	type Extra: for<'de> serde::Deserialize<'de>;
+
+	/// Body type
+	type Body: serde::Serialize;
+
+	/// Returns this request's url, given the base helix url
+	fn url_with_base(&self, base: &url::Url) -> url::Url;
+
+	/// Returns this request's url, using the default helix url
+	fn url(&self) -> url::Url {
+		self.url_with_base(&helix_url!())
+	}
 
 	/// Returns the request's http method
 	fn http_method(&self) -> HttpMethod;
+
+	/// Returns the request's body, if any
+	///
+	/// The body is sent as json. By default, requests don't have a body.
+	fn body(&self) -> Option<&Self::Body> {
+		None
+	}
+
+	/// Returns the scopes required by this request
+	///
+	/// By default, requests don't require any scopes.
+	fn required_scopes(&self) -> &'static [Scope] {
+		&[]
+	}
+
+	/// Returns scopes of which this request requires any one
+	///
+	/// This is for requests that accept several scopes, such as one
+	/// to read and another to manage. By default, this is empty.
+	fn any_required_scopes(&self) -> &'static [Scope] {
+		&[]
+	}
+}
+
+/// A paginated Helix request
+///
+/// Helix requests that return multiple pages accept an `after`
+/// cursor to request the next page, as given by the
+/// [`Pagination`](crate::response::Pagination) of the previous
+/// response, and a `first` argument for the maximum number of
+/// objects to return per page.
+///
+/// This allows the [`Client`](crate::Client) to follow every page
+/// of a request automatically.
+pub trait PaginatedRequest: HelixRequest {
+	/// Sets the cursor for forward pagination
+	fn set_after(&mut self, after: Option<String>);
+
+	/// Sets the maximum number of objects to return per page
+	fn set_first(&mut self, first: Option<usize>);
 }
 
 /// An OAuth request
@@ -48,12 +137,36 @@ pub trait HelixRequest {
 /// A [`OAuthResponse`](crate::OAuthResponse) is returned, that includes
 /// response type as it's `Ok` variant.
 ///
-/// An oauth request must simply return it's url, as all requests use
-/// the `Get` http method.
+/// An oauth request must simply return it's url, as most requests use
+/// the `Get` http method. Requests that don't must also return their http
+/// method.
+///
+/// As with [`HelixRequest`], the url is built on top of a base oauth url.
+///
+/// Requests that carry secrets, such as client secrets or tokens, send them
+/// as a form body instead of in the url, so they don't end up in any logs
+/// or errors that include it.
 pub trait OAuthRequest {
 	/// Response type
 	type Response: for<'de> serde::Deserialize<'de>;
 
-	/// Returns this request's url
-	fn url(&self) -> url::Url;
+	/// Returns this request's url, given the base oauth url
+	fn url_with_base(&self, base: &url::Url) -> url::Url;
+
+	/// Returns this request's url, using the default oauth url
+	fn url(&self) -> url::Url {
+		self.url_with_base(&oauth_url!())
+	}
+
+	/// Returns the request's http method
+	fn http_method(&self) -> HttpMethod {
+		HttpMethod::Get
+	}
+
+	/// Returns this request's form body, if any
+	///
+	/// It is sent as `application/x-www-form-urlencoded`.
+	fn form(&self) -> Option<Vec<(&'static str, String)>> {
+		None
+	}
 }
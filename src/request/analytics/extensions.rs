@@ -1,7 +1,7 @@
 //! Extension analytics request
 
 // Imports
-use crate::{helix_url, HelixRequest, HttpMethod};
+use crate::{helix_url, request::oauth::Scope, HelixRequest, HttpMethod, PaginatedRequest};
 
 /// Extension analytics request
 ///
@@ -155,10 +155,12 @@ impl Request {
 
 impl HelixRequest for Request {
 	type Response = Vec<Report>;
+	type Extra = ();
+	type Body = ();
 
-	fn url(&self) -> url::Url {
+	fn url_with_base(&self, base: &url::Url) -> url::Url {
 		// Append all our arguments if they exist
-		let mut url = helix_url!(analytics / extensions);
+		let mut url = helix_url!(base => analytics / extensions);
 
 		{
 			let mut query_pairs = url.query_pairs_mut();
@@ -191,6 +193,20 @@ impl HelixRequest for Request {
 	fn http_method(&self) -> HttpMethod {
 		HttpMethod::Get
 	}
+
+	fn required_scopes(&self) -> &'static [Scope] {
+		&[Scope::AnalyticsReadExtensions]
+	}
+}
+
+impl PaginatedRequest for Request {
+	fn set_after(&mut self, after: Option<String>) {
+		self.after = after;
+	}
+
+	fn set_first(&mut self, first: Option<usize>) {
+		self.first = first;
+	}
 }
 
 /// A Report
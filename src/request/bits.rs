@@ -1,4 +1,6 @@
 //! Bits requests
 
 // Modules
-//pub mod cheermotes;
+pub mod cheer;
+pub mod cheermotes;
+pub mod leaderboard;
@@ -0,0 +1,206 @@
+//! Cheer message parsing
+//!
+//! Chat messages may contain cheers, words made of a cheermote
+//! prefix followed by an amount of bits, such as `Cheer100`.
+//!
+//! Given the cheermotes returned by a [`cheermotes::Request`](super::cheermotes::Request),
+//! a message may be split into it's text and cheers.
+
+// Imports
+use super::cheermotes::{Cheermote, CheermoteTier};
+
+/// A parsed cheer message
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct CheerMessage<'m, 'c> {
+	/// All tokens, in order
+	pub tokens: Vec<Token<'m, 'c>>,
+
+	/// Total number of bits cheered
+	///
+	/// As messages may contain any amount, this saturates instead of overflowing.
+	pub total_bits: usize,
+}
+
+/// A token of a cheer message
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub enum Token<'m, 'c> {
+	/// Text, including any whitespace around it
+	Text(&'m str),
+
+	/// Cheer
+	Cheer(Cheer<'m, 'c>),
+}
+
+/// A cheer
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Cheer<'m, 'c> {
+	/// Cheer, as written in the message
+	pub text: &'m str,
+
+	/// Cheermote
+	pub cheermote: &'c Cheermote,
+
+	/// Number of bits
+	pub amount: usize,
+
+	/// Tier, the highest one the amount reaches
+	pub tier: &'c CheermoteTier,
+}
+
+impl<'m, 'c> CheerMessage<'m, 'c> {
+	/// Parses a message given all available cheermotes
+	///
+	/// Cheermote prefixes are matched without considering case.
+	/// Words that don't match any cheermote, or that don't reach
+	/// any of it's tiers, are kept as text.
+	///
+	/// # Examples
+	/// ```
+	/// # use twitch_helix::request::bits::cheer::{CheerMessage, Token};
+	/// # fn run(cheermotes: &[twitch_helix::request::bits::cheermotes::Cheermote]) {
+	/// let message = CheerMessage::parse("Cheer100 hype Kappa500", cheermotes);
+	/// for token in &message.tokens {
+	///   match token {
+	///     Token::Text(text) => println!("{}", text),
+	///     Token::Cheer(cheer) => println!("{} bits ({})", cheer.amount, cheer.tier.color),
+	///   }
+	/// }
+	/// println!("Total: {} bits", message.total_bits);
+	/// # }
+	/// ```
+	#[must_use]
+	pub fn parse(message: &'m str, cheermotes: &'c [Cheermote]) -> Self {
+		let mut tokens = vec![];
+		let mut total_bits: usize = 0;
+
+		// Check each word, keeping track of where the current text started
+		let mut text_start = 0;
+		let mut word_start = 0;
+		for word in message.split_inclusive(char::is_whitespace) {
+			let start = word_start;
+			word_start += word.len();
+
+			// If it's not a cheer, keep going
+			let word = word.trim_end_matches(char::is_whitespace);
+			let cheer = match Cheer::parse(word, cheermotes) {
+				Some(cheer) => cheer,
+				None => continue,
+			};
+
+			// Else add any text before it and the cheer
+			if text_start < start {
+				tokens.push(Token::Text(&message[text_start..start]));
+			}
+			total_bits = total_bits.saturating_add(cheer.amount);
+			tokens.push(Token::Cheer(cheer));
+			text_start = start + word.len();
+		}
+
+		// Then add any remaining text
+		if text_start < message.len() {
+			tokens.push(Token::Text(&message[text_start..]));
+		}
+
+		Self { tokens, total_bits }
+	}
+}
+
+impl<'m, 'c> Cheer<'m, 'c> {
+	/// Parses a single word as a cheer given all available cheermotes
+	#[must_use]
+	pub fn parse(word: &'m str, cheermotes: &'c [Cheermote]) -> Option<Self> {
+		// Split the word into it's prefix and amount
+		let prefix = word.trim_end_matches(|ch: char| ch.is_ascii_digit());
+		let amount: usize = word[prefix.len()..].parse().ok()?;
+		if prefix.is_empty() || amount == 0 {
+			return None;
+		}
+
+		// Then find the cheermote and the highest tier it reaches
+		let cheermote = cheermotes.iter().find(|cheermote| unicase::eq(cheermote.prefix.as_str(), prefix))?;
+		let tier = cheermote
+			.tiers
+			.iter()
+			.filter(|tier| tier.min_bits <= amount)
+			.max_by_key(|tier| tier.min_bits)?;
+
+		Some(Self {
+			text: word,
+			cheermote,
+			amount,
+			tier,
+		})
+	}
+}
+
+#[cfg(test)]
+mod test {
+	// Imports
+	use super::*;
+	use crate::response::ResponseData;
+
+	/// Returns the recorded cheermotes
+	fn cheermotes() -> Vec<Cheermote> {
+		let response: ResponseData<Vec<Cheermote>> =
+			serde_json::from_str(include_str!("../../../test-data/cheermotes.json")).expect("Unable to parse cheermotes");
+		response.data
+	}
+
+	/// Returns the cheer of a token
+	fn cheer<'a, 'm, 'c>(token: &'a Token<'m, 'c>) -> &'a Cheer<'m, 'c> {
+		match token {
+			Token::Cheer(cheer) => cheer,
+			Token::Text(text) => panic!("Expected cheer, found text {:?}", text),
+		}
+	}
+
+	#[test]
+	fn parse() {
+		let cheermotes = cheermotes();
+		let message = CheerMessage::parse("Cheer100 hype Kappa500", &cheermotes);
+
+		assert_eq!(message.tokens.len(), 3);
+		assert_eq!(message.total_bits, 600);
+
+		let cheer100 = cheer(&message.tokens[0]);
+		assert_eq!(cheer100.text, "Cheer100");
+		assert_eq!(cheer100.cheermote.prefix, "Cheer");
+		assert_eq!(cheer100.amount, 100);
+		assert_eq!(cheer100.tier.min_bits, 100);
+
+		assert_eq!(message.tokens[1], Token::Text(" hype "));
+
+		let kappa500 = cheer(&message.tokens[2]);
+		assert_eq!(kappa500.cheermote.prefix, "Kappa");
+		assert_eq!(kappa500.amount, 500);
+		assert_eq!(kappa500.tier.min_bits, 100);
+	}
+
+	#[test]
+	fn parse_case_insensitive() {
+		let cheermotes = cheermotes();
+		let message = CheerMessage::parse("cHeEr10000", &cheermotes);
+
+		assert_eq!(message.total_bits, 10000);
+		assert_eq!(cheer(&message.tokens[0]).tier.min_bits, 10000);
+	}
+
+	#[test]
+	fn parse_no_cheers() {
+		let cheermotes = cheermotes();
+		let message = CheerMessage::parse("Cheer Cheer0 Unknown100 100 hi", &cheermotes);
+
+		assert_eq!(message.tokens, vec![Token::Text("Cheer Cheer0 Unknown100 100 hi")]);
+		assert_eq!(message.total_bits, 0);
+	}
+
+	#[test]
+	fn parse_total_saturates() {
+		let cheermotes = cheermotes();
+		let message = format!("Cheer{} Cheer1", usize::MAX);
+		let message = CheerMessage::parse(&message, &cheermotes);
+
+		assert_eq!(message.tokens.len(), 3);
+		assert_eq!(message.total_bits, usize::MAX);
+	}
+}
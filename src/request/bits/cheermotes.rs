@@ -38,9 +38,11 @@ impl Request {
 
 impl HelixRequest for Request {
 	type Response = Vec<Cheermote>;
+	type Extra = ();
+	type Body = ();
 
-	fn url(&self) -> url::Url {
-		let mut url = helix_url!(bits / cheermotes);
+	fn url_with_base(&self, base: &url::Url) -> url::Url {
+		let mut url = helix_url!(base => bits / cheermotes);
 		url.query_pairs_mut().append_pair("broadcaster_id", &self.broadcaster_id);
 		url
 	}
@@ -55,21 +57,243 @@ impl HelixRequest for Request {
 #[derive(serde::Serialize, serde::Deserialize)]
 pub struct Cheermote {
 	/// Prefix
-	prefix: String,
+	pub prefix: String,
 
 	/// Tiers
-	tiers: Vec<CheermoteTier>,
+	pub tiers: Vec<CheermoteTier>,
 
 	/// Type
 	#[serde(rename = "type")]
-	cheermote_type: String,
+	pub cheermote_type: CheermoteType,
 
 	/// Order
-	order: usize,
-	///// Last updated
+	pub order: usize,
+
+	/// Last updated
+	pub last_updated: chrono::DateTime<chrono::Utc>,
+
+	/// If this cheermote is used for charitable causes
+	pub is_charitable: bool,
+}
+
+/// A cheermote type
+///
+/// Any type not known by this library is kept as [`CheermoteType::Unknown`].
+#[derive(PartialEq, Eq, Clone, Hash, Debug)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum CheermoteType {
+	/// Twitch-defined cheermote shown in the bits card
+	GlobalFirstParty,
+
+	/// Twitch-defined cheermote not shown in the bits card
+	GlobalThirdParty,
+
+	/// Broadcaster-defined cheermote
+	ChannelCustom,
+
+	/// Cheermote that can't be cheered with, only displayed
+	DisplayOnly,
+
+	/// Sponsor-defined cheermote
+	Sponsored,
+
+	/// Unknown type
+	Unknown(String),
+}
+
+impl CheermoteType {
+	/// Returns the name of this type
+	#[must_use]
+	pub fn as_str(&self) -> &str {
+		match self {
+			Self::GlobalFirstParty => "global_first_party",
+			Self::GlobalThirdParty => "global_third_party",
+			Self::ChannelCustom => "channel_custom",
+			Self::DisplayOnly => "display_only",
+			Self::Sponsored => "sponsored",
+			Self::Unknown(name) => name,
+		}
+	}
+}
+
+impl From<String> for CheermoteType {
+	fn from(name: String) -> Self {
+		match name.as_str() {
+			"global_first_party" => Self::GlobalFirstParty,
+			"global_third_party" => Self::GlobalThirdParty,
+			"channel_custom" => Self::ChannelCustom,
+			"display_only" => Self::DisplayOnly,
+			"sponsored" => Self::Sponsored,
+			_ => Self::Unknown(name),
+		}
+	}
+}
+
+impl From<CheermoteType> for String {
+	fn from(cheermote_type: CheermoteType) -> Self {
+		match cheermote_type {
+			CheermoteType::Unknown(name) => name,
+			cheermote_type => cheermote_type.as_str().to_owned(),
+		}
+	}
 }
 
 /// A cheermote tier
 #[derive(PartialEq, Eq, Clone, Debug)]
 #[derive(serde::Serialize, serde::Deserialize)]
-pub struct CheermoteTier {}
+pub struct CheermoteTier {
+	/// Minimum number of bits for this tier
+	pub min_bits: usize,
+
+	/// Id
+	pub id: String,
+
+	/// Hex color
+	pub color: String,
+
+	/// Images
+	pub images: CheermoteImages,
+
+	/// If this tier may be cheered with
+	pub can_cheer: bool,
+
+	/// If this tier is shown in the bits card
+	pub show_in_bits_card: bool,
+}
+
+/// Cheermote tier images for each theme
+#[derive(PartialEq, Eq, Clone, Debug)]
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct CheermoteImages {
+	/// Dark theme
+	pub dark: CheermoteImageFormats,
+
+	/// Light theme
+	pub light: CheermoteImageFormats,
+}
+
+/// Cheermote tier images for each format
+#[derive(PartialEq, Eq, Clone, Debug)]
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct CheermoteImageFormats {
+	/// Animated
+	pub animated: CheermoteImageScales,
+
+	/// Static
+	#[serde(rename = "static")]
+	pub still: CheermoteImageScales,
+}
+
+/// Cheermote tier image urls for each scale
+#[derive(PartialEq, Eq, Clone, Debug)]
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct CheermoteImageScales {
+	/// 1x scale
+	#[serde(rename = "1")]
+	pub scale1: String,
+
+	/// 1.5x scale
+	#[serde(rename = "1.5")]
+	pub scale1_5: String,
+
+	/// 2x scale
+	#[serde(rename = "2")]
+	pub scale2: String,
+
+	/// 3x scale
+	#[serde(rename = "3")]
+	pub scale3: String,
+
+	/// 4x scale
+	#[serde(rename = "4")]
+	pub scale4: String,
+}
+
+#[cfg(test)]
+mod test {
+	// Imports
+	use super::*;
+
+	/// Images of a tier, as returned by twitch
+	const IMAGES: &str = r#"{
+		"dark": {
+			"animated": {
+				"1": "https://d3aqoihi2n8ty8.cloudfront.net/actions/cheer/dark/animated/1/1.gif",
+				"1.5": "https://d3aqoihi2n8ty8.cloudfront.net/actions/cheer/dark/animated/1/1.5.gif",
+				"2": "https://d3aqoihi2n8ty8.cloudfront.net/actions/cheer/dark/animated/1/2.gif",
+				"3": "https://d3aqoihi2n8ty8.cloudfront.net/actions/cheer/dark/animated/1/3.gif",
+				"4": "https://d3aqoihi2n8ty8.cloudfront.net/actions/cheer/dark/animated/1/4.gif"
+			},
+			"static": {
+				"1": "https://d3aqoihi2n8ty8.cloudfront.net/actions/cheer/dark/static/1/1.png",
+				"1.5": "https://d3aqoihi2n8ty8.cloudfront.net/actions/cheer/dark/static/1/1.5.png",
+				"2": "https://d3aqoihi2n8ty8.cloudfront.net/actions/cheer/dark/static/1/2.png",
+				"3": "https://d3aqoihi2n8ty8.cloudfront.net/actions/cheer/dark/static/1/3.png",
+				"4": "https://d3aqoihi2n8ty8.cloudfront.net/actions/cheer/dark/static/1/4.png"
+			}
+		},
+		"light": {
+			"animated": {
+				"1": "https://d3aqoihi2n8ty8.cloudfront.net/actions/cheer/light/animated/1/1.gif",
+				"1.5": "https://d3aqoihi2n8ty8.cloudfront.net/actions/cheer/light/animated/1/1.5.gif",
+				"2": "https://d3aqoihi2n8ty8.cloudfront.net/actions/cheer/light/animated/1/2.gif",
+				"3": "https://d3aqoihi2n8ty8.cloudfront.net/actions/cheer/light/animated/1/3.gif",
+				"4": "https://d3aqoihi2n8ty8.cloudfront.net/actions/cheer/light/animated/1/4.gif"
+			},
+			"static": {
+				"1": "https://d3aqoihi2n8ty8.cloudfront.net/actions/cheer/light/static/1/1.png",
+				"1.5": "https://d3aqoihi2n8ty8.cloudfront.net/actions/cheer/light/static/1/1.5.png",
+				"2": "https://d3aqoihi2n8ty8.cloudfront.net/actions/cheer/light/static/1/2.png",
+				"3": "https://d3aqoihi2n8ty8.cloudfront.net/actions/cheer/light/static/1/3.png",
+				"4": "https://d3aqoihi2n8ty8.cloudfront.net/actions/cheer/light/static/1/4.png"
+			}
+		}
+	}"#;
+
+	#[test]
+	fn deserialize() {
+		let cheermote = format!(
+			r##"{{
+				"prefix": "Cheer",
+				"tiers": [{{
+					"min_bits": 1,
+					"id": "1",
+					"color": "#979797",
+					"images": {},
+					"can_cheer": true,
+					"show_in_bits_card": true
+				}}],
+				"type": "global_first_party",
+				"order": 1,
+				"last_updated": "2018-05-22T00:06:04Z",
+				"is_charitable": false
+			}}"##,
+			IMAGES
+		);
+
+		let cheermote: Cheermote = serde_json::from_str(&cheermote).expect("Unable to parse cheermote");
+		assert_eq!(cheermote.prefix, "Cheer");
+		assert_eq!(cheermote.cheermote_type, CheermoteType::GlobalFirstParty);
+		assert_eq!(cheermote.tiers.len(), 1);
+
+		let tier = &cheermote.tiers[0];
+		assert_eq!(tier.min_bits, 1);
+		assert_eq!(tier.color, "#979797");
+		assert!(tier.can_cheer);
+		assert_eq!(
+			tier.images.dark.still.scale1_5,
+			"https://d3aqoihi2n8ty8.cloudfront.net/actions/cheer/dark/static/1/1.5.png"
+		);
+	}
+
+	#[test]
+	fn deserialize_unknown_type() {
+		let cheermote_type: CheermoteType = serde_json::from_str("\"my_unknown_type\"").expect("Unable to parse type");
+		assert_eq!(cheermote_type, CheermoteType::Unknown("my_unknown_type".to_owned()));
+		assert_eq!(
+			serde_json::to_string(&cheermote_type).expect("Unable to serialize type"),
+			"\"my_unknown_type\""
+		);
+	}
+}
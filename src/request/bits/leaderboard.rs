@@ -0,0 +1,232 @@
+//! Bits leaderboard request
+
+// Imports
+use crate::{helix_url, request::oauth::Scope, HelixRequest, HttpMethod};
+
+/// Bits leaderboard request
+///
+/// This request uses the `/bits/leaderboard` path
+/// to get a ranked list of the users that cheered
+/// the most bits on the authenticated broadcaster's channel.
+///
+/// # Examples
+/// ```
+/// # use twitch_helix::request::bits::leaderboard::{Request, Period};
+/// # use twitch_helix::HelixRequest;
+/// let mut request = Request::new().with_count(25).with_period(Period::Week);
+///
+/// let url = request.url();
+/// assert_eq!(url.host_str(), Some("api.twitch.tv"));
+/// assert_eq!(url.path(), "/helix/bits/leaderboard");
+/// assert_eq!(url.query(), Some("count=25&period=week"));
+/// ```
+#[derive(PartialEq, Eq, Clone, Default, Debug)]
+pub struct Request {
+	/// Number of results to return
+	count: Option<usize>,
+
+	/// Time period over which data is aggregated
+	period: Option<Period>,
+
+	/// Timestamp for the period over which data is returned
+	started_at: Option<chrono::DateTime<chrono::Utc>>,
+
+	/// User id to get results for
+	user_id: Option<String>,
+}
+
+/// A leaderboard period
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Period {
+	/// Day, from 00:00:00 to 23:59:59
+	Day,
+
+	/// Week, from Monday 00:00:00 to Sunday 23:59:59
+	Week,
+
+	/// Month, from the first day of the month to the last
+	Month,
+
+	/// Year, from January 1st to December 31st
+	Year,
+
+	/// Lifetime of the broadcaster's channel
+	All,
+}
+
+impl Period {
+	/// Returns the string representation of this period
+	#[must_use]
+	pub const fn as_str(self) -> &'static str {
+		match self {
+			Self::Day => "day",
+			Self::Week => "week",
+			Self::Month => "month",
+			Self::Year => "year",
+			Self::All => "all",
+		}
+	}
+}
+
+impl Request {
+	/// Creates a new request with all parameters default
+	#[must_use]
+	pub fn new() -> Self {
+		Self {
+			count: None,
+			period: None,
+			started_at: None,
+			user_id: None,
+		}
+	}
+
+	/// Sets the number of results to return
+	#[must_use]
+	pub fn with_count(self, count: usize) -> Self {
+		Self { count: Some(count), ..self }
+	}
+
+	/// Sets the time period over which data is aggregated
+	#[must_use]
+	pub fn with_period(self, period: Period) -> Self {
+		Self {
+			period: Some(period),
+			..self
+		}
+	}
+
+	/// Sets the timestamp for the period over which data is returned
+	///
+	/// This is ignored if the period is [`Period::All`].
+	#[must_use]
+	pub fn with_started_at(self, started_at: chrono::DateTime<chrono::Utc>) -> Self {
+		Self {
+			started_at: Some(started_at),
+			..self
+		}
+	}
+
+	/// Sets the user id to get results for
+	///
+	/// The leaderboard is then centered on this user.
+	#[must_use]
+	pub fn with_user_id(self, user_id: impl Into<String>) -> Self {
+		Self {
+			user_id: Some(user_id.into()),
+			..self
+		}
+	}
+}
+
+impl HelixRequest for Request {
+	type Response = Vec<Entry>;
+	type Extra = Extra;
+	type Body = ();
+
+	fn url_with_base(&self, base: &url::Url) -> url::Url {
+		// Append all our arguments if they exist
+		let mut url = helix_url!(base => bits / leaderboard);
+
+		{
+			let mut query_pairs = url.query_pairs_mut();
+			if let Some(count) = &self.count {
+				query_pairs.append_pair("count", &count.to_string());
+			}
+			if let Some(period) = &self.period {
+				query_pairs.append_pair("period", period.as_str());
+			}
+			if let Some(started_at) = &self.started_at {
+				query_pairs.append_pair("started_at", &started_at.to_rfc3339());
+			}
+			if let Some(user_id) = &self.user_id {
+				query_pairs.append_pair("user_id", user_id);
+			}
+		}
+		url
+	}
+
+	fn http_method(&self) -> HttpMethod {
+		HttpMethod::Get
+	}
+
+	fn required_scopes(&self) -> &'static [Scope] {
+		&[Scope::BitsRead]
+	}
+}
+
+/// Each leaderboard entry in the output data
+#[derive(PartialEq, Eq, Clone, Debug)]
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct Entry {
+	/// User id
+	pub user_id: String,
+
+	/// User login name
+	pub user_login: String,
+
+	/// User display name
+	pub user_name: String,
+
+	/// Rank in the leaderboard
+	pub rank: usize,
+
+	/// Number of bits cheered
+	pub score: usize,
+}
+
+/// Extra fields returned alongside the leaderboard
+#[derive(PartialEq, Eq, Clone, Debug)]
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct Extra {
+	/// Period of the leaderboard
+	pub date_range: DateRange,
+
+	/// Number of entries in the leaderboard
+	pub total: usize,
+}
+
+/// Leaderboard period date range
+///
+/// If the period is [`Period::All`], both dates are empty.
+#[derive(PartialEq, Eq, Clone, Debug)]
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct DateRange {
+	/// Start of the period
+	#[serde(with = "crate::util::utc_date_time")]
+	pub started_at: Option<chrono::DateTime<chrono::Utc>>,
+
+	/// End of the period
+	#[serde(with = "crate::util::utc_date_time")]
+	pub ended_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+#[cfg(test)]
+mod test {
+	// Imports
+	use super::*;
+	use crate::response::ResponseData;
+
+	#[test]
+	fn deserialize() {
+		let response = r#"{
+			"data": [
+				{ "user_id": "158010205", "user_login": "tundracowboy", "user_name": "TundraCowboy", "rank": 1, "score": 12543 },
+				{ "user_id": "7168163", "user_login": "topramens", "user_name": "Topramens", "rank": 2, "score": 6900 }
+			],
+			"date_range": { "started_at": "2018-02-05T08:00:00Z", "ended_at": "2018-02-12T08:00:00Z" },
+			"total": 2
+		}"#;
+
+		let response: ResponseData<Vec<Entry>, Extra> = serde_json::from_str(response).expect("Unable to parse leaderboard");
+		assert_eq!(response.data.len(), 2);
+		assert_eq!(response.data[0].user_name, "TundraCowboy");
+		assert_eq!(response.data[1].rank, 2);
+		assert_eq!(response.extra.total, 2);
+		assert_eq!(
+			response.extra.date_range.started_at,
+			Some("2018-02-05T08:00:00Z".parse().expect("Invalid date-time"))
+		);
+	}
+}
@@ -0,0 +1,5 @@
+//! Channel points requests
+
+// Modules
+pub mod redemptions;
+pub mod rewards;
@@ -0,0 +1,161 @@
+//! Custom reward redemptions requests
+
+// Modules
+pub mod list;
+pub mod update;
+
+/// Maximum number of redemption ids per request
+pub const MAX_IDS: usize = 50;
+
+/// Error type for validating a request's redemption ids
+#[derive(PartialEq, Eq, Clone, Debug)]
+#[derive(thiserror::Error)]
+pub enum ValidationError {
+	/// No redemption ids
+	#[error("No redemption ids")]
+	NoIds,
+
+	/// Too many redemption ids
+	#[error("Too many redemption ids ({0})")]
+	TooManyIds(usize),
+}
+
+/// Collects and validates a request's redemption ids
+fn validate_ids<I>(ids: I) -> Result<Vec<String>, ValidationError>
+where
+	I: IntoIterator,
+	I::Item: Into<String>,
+{
+	let ids: Vec<String> = ids.into_iter().map(Into::into).collect();
+	if ids.is_empty() {
+		return Err(ValidationError::NoIds);
+	}
+	if ids.len() > MAX_IDS {
+		return Err(ValidationError::TooManyIds(ids.len()));
+	}
+
+	Ok(ids)
+}
+
+/// A custom reward redemption
+#[derive(PartialEq, Eq, Clone, Debug)]
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct Redemption {
+	/// Broadcaster id
+	pub broadcaster_id: String,
+
+	/// Broadcaster login name
+	pub broadcaster_login: String,
+
+	/// Broadcaster display name
+	pub broadcaster_name: String,
+
+	/// Id
+	pub id: String,
+
+	/// User id
+	pub user_id: String,
+
+	/// User login name
+	pub user_login: String,
+
+	/// User display name
+	pub user_name: String,
+
+	/// Information entered by the user
+	///
+	/// If the reward doesn't require any, this is empty.
+	pub user_input: String,
+
+	/// Status
+	pub status: Status,
+
+	/// When it was redeemed
+	pub redeemed_at: chrono::DateTime<chrono::Utc>,
+
+	/// Reward redeemed
+	pub reward: Reward,
+}
+
+/// A redemption status
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum Status {
+	/// Not yet fulfilled nor canceled
+	Unfulfilled,
+
+	/// Fulfilled
+	Fulfilled,
+
+	/// Canceled, with the channel points refunded
+	Canceled,
+}
+
+impl Status {
+	/// Returns the string representation of this status
+	#[must_use]
+	pub const fn as_str(self) -> &'static str {
+		match self {
+			Self::Unfulfilled => "UNFULFILLED",
+			Self::Fulfilled => "FULFILLED",
+			Self::Canceled => "CANCELED",
+		}
+	}
+}
+
+/// The reward of a redemption
+#[derive(PartialEq, Eq, Clone, Debug)]
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct Reward {
+	/// Id
+	pub id: String,
+
+	/// Title
+	pub title: String,
+
+	/// Prompt
+	pub prompt: String,
+
+	/// Cost, in channel points
+	pub cost: u64,
+}
+
+#[cfg(test)]
+mod test {
+	// Imports
+	use super::*;
+	use crate::response::ResponseData;
+
+	#[test]
+	fn deserialize() {
+		let response = r#"{
+			"data": [{
+				"broadcaster_name": "torpedo09",
+				"broadcaster_login": "torpedo09",
+				"broadcaster_id": "274637212",
+				"id": "17fa2df1-ad76-4804-bfa5-a40ef63efe63",
+				"user_login": "torpedo09",
+				"user_id": "274637212",
+				"user_name": "torpedo09",
+				"user_input": "never gonna give you up",
+				"status": "UNFULFILLED",
+				"redeemed_at": "2020-07-01T18:37:32Z",
+				"reward": {
+					"id": "92af127c-7326-4483-a52b-b0da0be61c01",
+					"title": "song request",
+					"prompt": "",
+					"cost": 50000
+				}
+			}],
+			"pagination": { "cursor": "eyJiIjpudWxsLCJhIjp7IkN1cnNvciI6Ik1qYzBOak0zTWpFeVh6RTNabUV5WkdZeExXRmtOell0TkRnd05DMWlabUUxTFdFME1HVm1OakkxWldZMk13PT0ifX0" }
+		}"#;
+
+		let response: ResponseData<Vec<Redemption>> = serde_json::from_str(response).expect("Unable to parse redemptions");
+		let redemption = &response.data[0];
+		assert_eq!(redemption.user_input, "never gonna give you up");
+		assert_eq!(redemption.status, Status::Unfulfilled);
+		assert_eq!(redemption.reward.title, "song request");
+		assert_eq!(redemption.reward.cost, 50000);
+	}
+}
@@ -0,0 +1,226 @@
+//! List custom reward redemptions request
+
+// Imports
+use super::{Redemption, Status, ValidationError};
+use crate::{helix_url, request::oauth::Scope, HelixRequest, HttpMethod, PaginatedRequest};
+
+/// List custom reward redemptions request
+///
+/// This request uses the `/channel_points/custom_rewards/redemptions` path
+/// to get the redemptions of a custom reward.
+///
+/// Either a status, or redemption ids, must be given, so the
+/// request is created with either [`Request::by_status`] or
+/// [`Request::by_ids`].
+///
+/// # Examples
+/// Simple request:
+/// ```
+/// # use twitch_helix::request::channel_points::redemptions::{list::Request, Status};
+/// # use twitch_helix::HelixRequest;
+/// let mut request = Request::by_status("my-channel-id", "my-reward-id", Status::Unfulfilled);
+///
+/// let url = request.url();
+/// assert_eq!(url.host_str(), Some("api.twitch.tv"));
+/// assert_eq!(url.path(), "/helix/channel_points/custom_rewards/redemptions");
+/// assert_eq!(url.query(), Some("broadcaster_id=my-channel-id&reward_id=my-reward-id&status=UNFULFILLED"));
+/// ```
+///
+/// Using every argument:
+/// ```
+/// # use twitch_helix::request::channel_points::redemptions::{list::{Request, Sort}, Status, ValidationError};
+/// # use twitch_helix::HelixRequest;
+/// # fn main() -> Result<(), ValidationError> {
+/// let mut request = Request::by_ids("my-channel-id", "my-reward-id", vec!["my-redemption-id"])?
+///   .with_status(Status::Fulfilled)
+///   .with_sort(Sort::Newest)
+///   .with_first(50)
+///   .with_after("my-cursor".to_string());
+///
+/// let url = request.url();
+/// assert_eq!(url.host_str(), Some("api.twitch.tv"));
+/// assert_eq!(url.path(), "/helix/channel_points/custom_rewards/redemptions");
+/// assert_eq!(
+///   url.query(),
+///   Some("broadcaster_id=my-channel-id&reward_id=my-reward-id&status=FULFILLED&id=my-redemption-id&sort=NEWEST&first=50&after=my-cursor")
+/// );
+/// # Ok(())
+/// # }
+/// ```
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Request {
+	/// Broadcaster ID
+	broadcaster_id: String,
+
+	/// Reward id
+	reward_id: String,
+
+	/// Status of the redemptions to return
+	status: Option<Status>,
+
+	/// Redemption ids
+	ids: Vec<String>,
+
+	/// Order of the redemptions
+	sort: Option<Sort>,
+
+	/// Maximum number of objects to return
+	first: Option<usize>,
+
+	/// Cursor for forward pagination
+	after: Option<String>,
+}
+
+/// A redemption order
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum Sort {
+	/// Oldest first
+	Oldest,
+
+	/// Newest first
+	Newest,
+}
+
+impl Sort {
+	/// Returns the string representation of this order
+	#[must_use]
+	pub const fn as_str(self) -> &'static str {
+		match self {
+			Self::Oldest => "OLDEST",
+			Self::Newest => "NEWEST",
+		}
+	}
+}
+
+impl Request {
+	/// Creates a new request for the redemptions with a status
+	#[must_use]
+	pub fn by_status(broadcaster_id: impl Into<String>, reward_id: impl Into<String>, status: Status) -> Self {
+		Self {
+			broadcaster_id: broadcaster_id.into(),
+			reward_id: reward_id.into(),
+			status: Some(status),
+			ids: vec![],
+			sort: None,
+			first: None,
+			after: None,
+		}
+	}
+
+	/// Creates a new request for specific redemptions
+	///
+	/// Up to [`MAX_IDS`](super::MAX_IDS) redemptions may be requested at once.
+	pub fn by_ids<I>(broadcaster_id: impl Into<String>, reward_id: impl Into<String>, ids: I) -> Result<Self, ValidationError>
+	where
+		I: IntoIterator,
+		I::Item: Into<String>,
+	{
+		Ok(Self {
+			broadcaster_id: broadcaster_id.into(),
+			reward_id: reward_id.into(),
+			status: None,
+			ids: super::validate_ids(ids)?,
+			sort: None,
+			first: None,
+			after: None,
+		})
+	}
+
+	/// Sets the status of the redemptions to return
+	#[must_use]
+	pub fn with_status(self, status: Status) -> Self {
+		Self {
+			status: Some(status),
+			..self
+		}
+	}
+
+	/// Sets the order of the redemptions
+	#[must_use]
+	pub fn with_sort(self, sort: Sort) -> Self {
+		Self { sort: Some(sort), ..self }
+	}
+
+	/// Sets the maximum number of objects to return
+	#[must_use]
+	pub fn with_first(self, first: usize) -> Self {
+		Self { first: Some(first), ..self }
+	}
+
+	/// Sets the cursor for forward pagination
+	#[must_use]
+	pub fn with_after(self, after: String) -> Self {
+		Self { after: Some(after), ..self }
+	}
+}
+
+impl HelixRequest for Request {
+	type Response = Vec<Redemption>;
+	type Extra = ();
+	type Body = ();
+
+	fn url_with_base(&self, base: &url::Url) -> url::Url {
+		// Append all our arguments if they exist
+		let mut url = helix_url!(base => channel_points / custom_rewards / redemptions);
+
+		{
+			let mut query_pairs = url.query_pairs_mut();
+			query_pairs.append_pair("broadcaster_id", &self.broadcaster_id);
+			query_pairs.append_pair("reward_id", &self.reward_id);
+			if let Some(status) = &self.status {
+				query_pairs.append_pair("status", status.as_str());
+			}
+			for id in &self.ids {
+				query_pairs.append_pair("id", id);
+			}
+			if let Some(sort) = &self.sort {
+				query_pairs.append_pair("sort", sort.as_str());
+			}
+			if let Some(first) = &self.first {
+				query_pairs.append_pair("first", &first.to_string());
+			}
+			if let Some(after) = &self.after {
+				query_pairs.append_pair("after", after);
+			}
+		}
+		url
+	}
+
+	fn http_method(&self) -> HttpMethod {
+		HttpMethod::Get
+	}
+
+	fn any_required_scopes(&self) -> &'static [Scope] {
+		&[Scope::ChannelReadRedemptions, Scope::ChannelManageRedemptions]
+	}
+}
+
+impl PaginatedRequest for Request {
+	fn set_after(&mut self, after: Option<String>) {
+		self.after = after;
+	}
+
+	fn set_first(&mut self, first: Option<usize>) {
+		self.first = first;
+	}
+}
+
+#[cfg(test)]
+mod test {
+	// Imports
+	use super::{super::MAX_IDS, *};
+
+	#[test]
+	fn validation() {
+		assert_eq!(
+			Request::by_ids("my-channel-id", "my-reward-id", Vec::<String>::new()),
+			Err(ValidationError::NoIds)
+		);
+		assert_eq!(
+			Request::by_ids("my-channel-id", "my-reward-id", vec!["my-redemption-id"; MAX_IDS + 1]),
+			Err(ValidationError::TooManyIds(MAX_IDS + 1))
+		);
+	}
+}
@@ -0,0 +1,144 @@
+//! Update custom reward redemptions request
+
+// Imports
+use super::{Redemption, Status, ValidationError};
+use crate::{helix_url, request::oauth::Scope, HelixRequest, HttpMethod};
+
+/// Update custom reward redemptions request
+///
+/// This request uses the `/channel_points/custom_rewards/redemptions` path
+/// to mark redemptions of a custom reward as fulfilled or canceled.
+///
+/// Canceling a redemption refunds the user's channel points.
+///
+/// Only redemptions of rewards created by the same client id may be updated.
+///
+/// # Examples
+/// ```
+/// # use twitch_helix::request::channel_points::redemptions::{update::Request, ValidationError};
+/// # use twitch_helix::HelixRequest;
+/// # fn main() -> Result<(), ValidationError> {
+/// let mut request = Request::cancel("my-channel-id", "my-reward-id", vec!["my-redemption-id", "my-other-redemption-id"])?;
+///
+/// let url = request.url();
+/// assert_eq!(url.host_str(), Some("api.twitch.tv"));
+/// assert_eq!(url.path(), "/helix/channel_points/custom_rewards/redemptions");
+/// assert_eq!(
+///   url.query(),
+///   Some("broadcaster_id=my-channel-id&reward_id=my-reward-id&id=my-redemption-id&id=my-other-redemption-id")
+/// );
+///
+/// let body = serde_json::to_value(request.body()).expect("Unable to serialize body");
+/// assert_eq!(body, serde_json::json!({ "status": "CANCELED" }));
+/// # Ok(())
+/// # }
+/// ```
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Request {
+	/// Broadcaster ID
+	broadcaster_id: String,
+
+	/// Reward id
+	reward_id: String,
+
+	/// Redemption ids
+	ids: Vec<String>,
+
+	/// Body
+	body: Body,
+}
+
+/// Request body
+#[derive(PartialEq, Eq, Clone, Debug)]
+#[derive(serde::Serialize)]
+pub struct Body {
+	/// New status
+	status: Status,
+}
+
+impl Request {
+	/// Creates a new request to mark redemptions as fulfilled
+	///
+	/// Up to [`MAX_IDS`](super::MAX_IDS) redemptions may be updated at once.
+	pub fn fulfill<I>(broadcaster_id: impl Into<String>, reward_id: impl Into<String>, ids: I) -> Result<Self, ValidationError>
+	where
+		I: IntoIterator,
+		I::Item: Into<String>,
+	{
+		Self::new(broadcaster_id.into(), reward_id.into(), ids, Status::Fulfilled)
+	}
+
+	/// Creates a new request to mark redemptions as canceled
+	///
+	/// Up to [`MAX_IDS`](super::MAX_IDS) redemptions may be updated at once.
+	pub fn cancel<I>(broadcaster_id: impl Into<String>, reward_id: impl Into<String>, ids: I) -> Result<Self, ValidationError>
+	where
+		I: IntoIterator,
+		I::Item: Into<String>,
+	{
+		Self::new(broadcaster_id.into(), reward_id.into(), ids, Status::Canceled)
+	}
+
+	/// Creates a new request given the new status
+	fn new<I>(broadcaster_id: String, reward_id: String, ids: I, status: Status) -> Result<Self, ValidationError>
+	where
+		I: IntoIterator,
+		I::Item: Into<String>,
+	{
+		Ok(Self {
+			broadcaster_id,
+			reward_id,
+			ids: super::validate_ids(ids)?,
+			body: Body { status },
+		})
+	}
+}
+
+impl HelixRequest for Request {
+	type Response = Vec<Redemption>;
+	type Extra = ();
+	type Body = Body;
+
+	fn url_with_base(&self, base: &url::Url) -> url::Url {
+		let mut url = helix_url!(base => channel_points / custom_rewards / redemptions);
+		{
+			let mut query_pairs = url.query_pairs_mut();
+			query_pairs.append_pair("broadcaster_id", &self.broadcaster_id);
+			query_pairs.append_pair("reward_id", &self.reward_id);
+			for id in &self.ids {
+				query_pairs.append_pair("id", id);
+			}
+		}
+		url
+	}
+
+	fn http_method(&self) -> HttpMethod {
+		HttpMethod::Patch
+	}
+
+	fn body(&self) -> Option<&Self::Body> {
+		Some(&self.body)
+	}
+
+	fn required_scopes(&self) -> &'static [Scope] {
+		&[Scope::ChannelManageRedemptions]
+	}
+}
+
+#[cfg(test)]
+mod test {
+	// Imports
+	use super::*;
+
+	#[test]
+	fn validation() {
+		assert_eq!(
+			Request::fulfill("my-channel-id", "my-reward-id", Vec::<String>::new()),
+			Err(ValidationError::NoIds)
+		);
+		assert_eq!(
+			Request::fulfill("my-channel-id", "my-reward-id", vec!["my-redemption-id"; 51]),
+			Err(ValidationError::TooManyIds(51))
+		);
+	}
+}
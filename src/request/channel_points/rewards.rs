@@ -0,0 +1,235 @@
+//! Custom rewards requests
+
+// Modules
+pub mod create;
+pub mod delete;
+pub mod list;
+pub mod update;
+
+// Imports
+use std::time::Duration;
+
+/// A custom reward
+#[derive(PartialEq, Eq, Clone, Debug)]
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct CustomReward {
+	/// Broadcaster id
+	pub broadcaster_id: String,
+
+	/// Broadcaster login name
+	pub broadcaster_login: String,
+
+	/// Broadcaster display name
+	pub broadcaster_name: String,
+
+	/// Id
+	pub id: String,
+
+	/// Title
+	pub title: String,
+
+	/// Prompt shown to the viewer when redeeming
+	pub prompt: String,
+
+	/// Cost, in channel points
+	pub cost: u64,
+
+	/// Custom images, if any
+	pub image: Option<RewardImage>,
+
+	/// Default images
+	pub default_image: RewardImage,
+
+	/// Background hex color
+	pub background_color: String,
+
+	/// If enabled
+	pub is_enabled: bool,
+
+	/// If the user must enter information when redeeming
+	pub is_user_input_required: bool,
+
+	/// Maximum number of redemptions per stream
+	pub max_per_stream_setting: MaxPerStreamSetting,
+
+	/// Maximum number of redemptions per user per stream
+	pub max_per_user_per_stream_setting: MaxPerUserPerStreamSetting,
+
+	/// Cooldown between redemptions
+	pub global_cooldown_setting: GlobalCooldownSetting,
+
+	/// If paused
+	pub is_paused: bool,
+
+	/// If it may currently be redeemed
+	pub is_in_stock: bool,
+
+	/// If redemptions skip the request queue, being fulfilled immediately
+	pub should_redemptions_skip_request_queue: bool,
+
+	/// Number of redemptions in the current stream
+	///
+	/// If the channel isn't live, or there is no maximum per stream, this is `None`.
+	pub redemptions_redeemed_current_stream: Option<u64>,
+
+	/// When the cooldown expires
+	///
+	/// If not in cooldown, this is `None`.
+	pub cooldown_expires_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+/// Custom reward image urls for each scale
+#[derive(PartialEq, Eq, Clone, Debug)]
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct RewardImage {
+	/// 1x scale
+	pub url_1x: String,
+
+	/// 2x scale
+	pub url_2x: String,
+
+	/// 4x scale
+	pub url_4x: String,
+}
+
+/// Custom reward maximum redemptions per stream setting
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct MaxPerStreamSetting {
+	/// If enabled
+	pub is_enabled: bool,
+
+	/// Maximum
+	pub max_per_stream: u64,
+}
+
+/// Custom reward maximum redemptions per user per stream setting
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct MaxPerUserPerStreamSetting {
+	/// If enabled
+	pub is_enabled: bool,
+
+	/// Maximum
+	pub max_per_user_per_stream: u64,
+}
+
+/// Custom reward cooldown setting
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct GlobalCooldownSetting {
+	/// If enabled
+	pub is_enabled: bool,
+
+	/// Cooldown
+	#[serde(rename = "global_cooldown_seconds", with = "crate::util::duration_secs")]
+	pub global_cooldown: Duration,
+}
+
+/// Custom reward settings
+///
+/// Used when creating or updating a custom reward.
+/// Only the settings set are sent.
+#[derive(PartialEq, Eq, Clone, Default, Debug)]
+#[derive(serde::Serialize)]
+pub struct RewardSettings {
+	/// Prompt shown to the viewer when redeeming
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub prompt: Option<String>,
+
+	/// If enabled
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub is_enabled: Option<bool>,
+
+	/// Background hex color
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub background_color: Option<String>,
+
+	/// If the user must enter information when redeeming
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub is_user_input_required: Option<bool>,
+
+	/// If the maximum number of redemptions per stream is enabled
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub is_max_per_stream_enabled: Option<bool>,
+
+	/// Maximum number of redemptions per stream
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub max_per_stream: Option<u64>,
+
+	/// If the maximum number of redemptions per user per stream is enabled
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub is_max_per_user_per_stream_enabled: Option<bool>,
+
+	/// Maximum number of redemptions per user per stream
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub max_per_user_per_stream: Option<u64>,
+
+	/// If the cooldown is enabled
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub is_global_cooldown_enabled: Option<bool>,
+
+	/// Cooldown
+	#[serde(
+		rename = "global_cooldown_seconds",
+		skip_serializing_if = "Option::is_none",
+		serialize_with = "serialize_cooldown"
+	)]
+	pub global_cooldown: Option<Duration>,
+
+	/// If redemptions skip the request queue, being fulfilled immediately
+	#[serde(skip_serializing_if = "Option::is_none")]
+	pub should_redemptions_skip_request_queue: Option<bool>,
+}
+
+/// Serializes the cooldown of the reward settings
+fn serialize_cooldown<S: serde::Serializer>(global_cooldown: &Option<Duration>, serializer: S) -> Result<S::Ok, S::Error> {
+	match global_cooldown {
+		Some(global_cooldown) => crate::util::duration_secs::serialize(global_cooldown, serializer),
+		None => serializer.serialize_none(),
+	}
+}
+
+#[cfg(test)]
+mod test {
+	// Imports
+	use super::*;
+	use crate::response::ResponseData;
+
+	#[test]
+	fn deserialize() {
+		let response: ResponseData<Vec<CustomReward>> =
+			serde_json::from_str(include_str!("../../../test-data/custom_rewards.json")).expect("Unable to parse custom rewards");
+		assert_eq!(response.data.len(), 1);
+
+		let reward = &response.data[0];
+		assert_eq!(reward.title, "game analysis 1v1");
+		assert_eq!(reward.cost, 50000);
+		assert_eq!(reward.image, None);
+		assert_eq!(
+			reward.default_image.url_1x,
+			"https://static-cdn.jtvnw.net/custom-reward-images/default-1.png"
+		);
+		assert_eq!(reward.global_cooldown_setting.global_cooldown, Duration::from_secs(60));
+		assert_eq!(reward.cooldown_expires_at, None);
+	}
+
+	#[test]
+	fn serialize_settings() {
+		let settings = RewardSettings {
+			prompt: Some("Pick a song".to_owned()),
+			is_global_cooldown_enabled: Some(true),
+			global_cooldown: Some(Duration::from_secs(300)),
+			..RewardSettings::default()
+		};
+
+		assert_eq!(
+			serde_json::to_value(&settings).expect("Unable to serialize settings"),
+			serde_json::json!({
+				"prompt": "Pick a song",
+				"is_global_cooldown_enabled": true,
+				"global_cooldown_seconds": 300,
+			})
+		);
+	}
+}
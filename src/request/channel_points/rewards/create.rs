@@ -0,0 +1,99 @@
+//! Create custom reward request
+
+// Imports
+use super::{CustomReward, RewardSettings};
+use crate::{helix_url, request::oauth::Scope, HelixRequest, HttpMethod};
+
+/// Create custom reward request
+///
+/// This request uses the `/channel_points/custom_rewards` path
+/// to create a custom reward on a channel.
+///
+/// # Examples
+/// ```
+/// # use twitch_helix::request::channel_points::rewards::{create::Request, RewardSettings};
+/// # use twitch_helix::HelixRequest;
+/// let mut request = Request::new("my-channel-id", "My reward", 100).with_settings(RewardSettings {
+///   is_user_input_required: Some(true),
+///   ..RewardSettings::default()
+/// });
+///
+/// let url = request.url();
+/// assert_eq!(url.host_str(), Some("api.twitch.tv"));
+/// assert_eq!(url.path(), "/helix/channel_points/custom_rewards");
+/// assert_eq!(url.query(), Some("broadcaster_id=my-channel-id"));
+///
+/// let body = serde_json::to_value(request.body()).expect("Unable to serialize body");
+/// assert_eq!(body["title"], "My reward");
+/// assert_eq!(body["cost"], 100);
+/// assert_eq!(body["is_user_input_required"], true);
+/// ```
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Request {
+	/// Broadcaster ID
+	broadcaster_id: String,
+
+	/// Body
+	body: Body,
+}
+
+/// Request body
+#[derive(PartialEq, Eq, Clone, Debug)]
+#[derive(serde::Serialize)]
+pub struct Body {
+	/// Title
+	title: String,
+
+	/// Cost, in channel points
+	cost: u64,
+
+	/// Settings
+	#[serde(flatten)]
+	settings: RewardSettings,
+}
+
+impl Request {
+	/// Creates a new request
+	#[must_use]
+	pub fn new(broadcaster_id: impl Into<String>, title: impl Into<String>, cost: u64) -> Self {
+		Self {
+			broadcaster_id: broadcaster_id.into(),
+			body: Body {
+				title: title.into(),
+				cost,
+				settings: RewardSettings::default(),
+			},
+		}
+	}
+
+	/// Sets the reward's settings
+	#[must_use]
+	pub fn with_settings(mut self, settings: RewardSettings) -> Self {
+		self.body.settings = settings;
+		self
+	}
+}
+
+impl HelixRequest for Request {
+	type Response = [CustomReward; 1];
+	type Extra = ();
+	type Body = Body;
+
+	fn url_with_base(&self, base: &url::Url) -> url::Url {
+		let mut url = helix_url!(base => channel_points / custom_rewards);
+		url.query_pairs_mut().append_pair("broadcaster_id", &self.broadcaster_id);
+		url
+	}
+
+	fn http_method(&self) -> HttpMethod {
+		HttpMethod::Post
+	}
+
+	fn body(&self) -> Option<&Self::Body> {
+		Some(&self.body)
+	}
+
+	fn required_scopes(&self) -> &'static [Scope] {
+		&[Scope::ChannelManageRedemptions]
+	}
+}
@@ -0,0 +1,68 @@
+//! Delete custom reward request
+
+// Imports
+use crate::{helix_url, request::oauth::Scope, HelixRequest, HttpMethod};
+
+/// Delete custom reward request
+///
+/// This request uses the `/channel_points/custom_rewards` path
+/// to delete a custom reward from a channel.
+///
+/// Only rewards created by the same client id may be deleted.
+///
+/// Twitch returns no data for this request.
+///
+/// # Examples
+/// ```
+/// # use twitch_helix::request::channel_points::rewards::delete::Request;
+/// # use twitch_helix::HelixRequest;
+/// let mut request = Request::new("my-channel-id", "my-reward-id");
+///
+/// let url = request.url();
+/// assert_eq!(url.host_str(), Some("api.twitch.tv"));
+/// assert_eq!(url.path(), "/helix/channel_points/custom_rewards");
+/// assert_eq!(url.query(), Some("broadcaster_id=my-channel-id&id=my-reward-id"));
+/// ```
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Request {
+	/// Broadcaster ID
+	broadcaster_id: String,
+
+	/// Reward id
+	id: String,
+}
+
+impl Request {
+	/// Creates a new request
+	#[must_use]
+	pub fn new(broadcaster_id: impl Into<String>, id: impl Into<String>) -> Self {
+		Self {
+			broadcaster_id: broadcaster_id.into(),
+			id: id.into(),
+		}
+	}
+}
+
+impl HelixRequest for Request {
+	type Response = ();
+	type Extra = ();
+	type Body = ();
+
+	fn url_with_base(&self, base: &url::Url) -> url::Url {
+		let mut url = helix_url!(base => channel_points / custom_rewards);
+		{
+			let mut query_pairs = url.query_pairs_mut();
+			query_pairs.append_pair("broadcaster_id", &self.broadcaster_id);
+			query_pairs.append_pair("id", &self.id);
+		}
+		url
+	}
+
+	fn http_method(&self) -> HttpMethod {
+		HttpMethod::Delete
+	}
+
+	fn required_scopes(&self) -> &'static [Scope] {
+		&[Scope::ChannelManageRedemptions]
+	}
+}
@@ -0,0 +1,117 @@
+//! List custom rewards request
+
+// Imports
+use super::CustomReward;
+use crate::{helix_url, request::oauth::Scope, HelixRequest, HttpMethod};
+
+/// Maximum number of reward ids per request
+pub const MAX_IDS: usize = 50;
+
+/// List custom rewards request
+///
+/// This request uses the `/channel_points/custom_rewards` path
+/// to get the custom rewards of a channel.
+///
+/// # Examples
+/// Simple request:
+/// ```
+/// # use twitch_helix::request::channel_points::rewards::list::Request;
+/// # use twitch_helix::HelixRequest;
+/// let mut request = Request::new("my-channel-id");
+///
+/// let url = request.url();
+/// assert_eq!(url.host_str(), Some("api.twitch.tv"));
+/// assert_eq!(url.path(), "/helix/channel_points/custom_rewards");
+/// assert_eq!(url.query(), Some("broadcaster_id=my-channel-id"));
+/// ```
+///
+/// Using every argument:
+/// ```
+/// # use twitch_helix::request::channel_points::rewards::list::Request;
+/// # use twitch_helix::HelixRequest;
+/// let mut request = Request::new("my-channel-id")
+///   .with_id("my-reward-id")
+///   .with_id("my-other-reward-id")
+///   .with_only_manageable_rewards(true);
+///
+/// let url = request.url();
+/// assert_eq!(url.host_str(), Some("api.twitch.tv"));
+/// assert_eq!(url.path(), "/helix/channel_points/custom_rewards");
+/// assert_eq!(
+///   url.query(),
+///   Some("broadcaster_id=my-channel-id&id=my-reward-id&id=my-other-reward-id&only_manageable_rewards=true")
+/// );
+/// ```
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Request {
+	/// Broadcaster ID
+	broadcaster_id: String,
+
+	/// Reward ids
+	ids: Vec<String>,
+
+	/// If only rewards that may be managed by this client id are returned
+	only_manageable_rewards: Option<bool>,
+}
+
+impl Request {
+	/// Creates a new request
+	#[must_use]
+	pub fn new(broadcaster_id: impl Into<String>) -> Self {
+		Self {
+			broadcaster_id: broadcaster_id.into(),
+			ids: vec![],
+			only_manageable_rewards: None,
+		}
+	}
+
+	/// Adds a reward id to request
+	///
+	/// If none are added, all rewards are returned.
+	/// Up to [`MAX_IDS`] may be added.
+	#[must_use]
+	pub fn with_id(mut self, id: impl Into<String>) -> Self {
+		self.ids.push(id.into());
+		self
+	}
+
+	/// Sets if only rewards that may be managed by this client id are returned
+	#[must_use]
+	pub fn with_only_manageable_rewards(self, only_manageable_rewards: bool) -> Self {
+		Self {
+			only_manageable_rewards: Some(only_manageable_rewards),
+			..self
+		}
+	}
+}
+
+impl HelixRequest for Request {
+	type Response = Vec<CustomReward>;
+	type Extra = ();
+	type Body = ();
+
+	fn url_with_base(&self, base: &url::Url) -> url::Url {
+		// Append all our arguments if they exist
+		let mut url = helix_url!(base => channel_points / custom_rewards);
+
+		{
+			let mut query_pairs = url.query_pairs_mut();
+			query_pairs.append_pair("broadcaster_id", &self.broadcaster_id);
+			for id in &self.ids {
+				query_pairs.append_pair("id", id);
+			}
+			if let Some(only_manageable_rewards) = &self.only_manageable_rewards {
+				query_pairs.append_pair("only_manageable_rewards", &only_manageable_rewards.to_string());
+			}
+		}
+		url
+	}
+
+	fn http_method(&self) -> HttpMethod {
+		HttpMethod::Get
+	}
+
+	fn any_required_scopes(&self) -> &'static [Scope] {
+		&[Scope::ChannelReadRedemptions, Scope::ChannelManageRedemptions]
+	}
+}
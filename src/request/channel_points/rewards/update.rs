@@ -0,0 +1,130 @@
+//! Update custom reward request
+
+// Imports
+use super::{CustomReward, RewardSettings};
+use crate::{helix_url, request::oauth::Scope, HelixRequest, HttpMethod};
+
+/// Update custom reward request
+///
+/// This request uses the `/channel_points/custom_rewards` path
+/// to update a custom reward on a channel.
+///
+/// Only the fields set are sent, with the rest
+/// being left as they are.
+///
+/// Only rewards created by the same client id may be updated.
+///
+/// # Examples
+/// ```
+/// # use twitch_helix::request::channel_points::rewards::update::Request;
+/// # use twitch_helix::HelixRequest;
+/// let mut request = Request::new("my-channel-id", "my-reward-id").with_cost(200).with_is_paused(true);
+///
+/// let url = request.url();
+/// assert_eq!(url.host_str(), Some("api.twitch.tv"));
+/// assert_eq!(url.path(), "/helix/channel_points/custom_rewards");
+/// assert_eq!(url.query(), Some("broadcaster_id=my-channel-id&id=my-reward-id"));
+///
+/// let body = serde_json::to_value(request.body()).expect("Unable to serialize body");
+/// assert_eq!(body, serde_json::json!({ "cost": 200, "is_paused": true }));
+/// ```
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Request {
+	/// Broadcaster ID
+	broadcaster_id: String,
+
+	/// Reward id
+	id: String,
+
+	/// Body
+	body: Body,
+}
+
+/// Request body
+#[derive(PartialEq, Eq, Clone, Default, Debug)]
+#[derive(serde::Serialize)]
+pub struct Body {
+	/// Title
+	#[serde(skip_serializing_if = "Option::is_none")]
+	title: Option<String>,
+
+	/// Cost, in channel points
+	#[serde(skip_serializing_if = "Option::is_none")]
+	cost: Option<u64>,
+
+	/// If paused
+	#[serde(skip_serializing_if = "Option::is_none")]
+	is_paused: Option<bool>,
+
+	/// Settings
+	#[serde(flatten)]
+	settings: RewardSettings,
+}
+
+impl Request {
+	/// Creates a new request, without any fields set
+	#[must_use]
+	pub fn new(broadcaster_id: impl Into<String>, id: impl Into<String>) -> Self {
+		Self {
+			broadcaster_id: broadcaster_id.into(),
+			id: id.into(),
+			body: Body::default(),
+		}
+	}
+
+	/// Sets the title
+	#[must_use]
+	pub fn with_title(mut self, title: impl Into<String>) -> Self {
+		self.body.title = Some(title.into());
+		self
+	}
+
+	/// Sets the cost, in channel points
+	#[must_use]
+	pub fn with_cost(mut self, cost: u64) -> Self {
+		self.body.cost = Some(cost);
+		self
+	}
+
+	/// Sets if paused
+	#[must_use]
+	pub fn with_is_paused(mut self, is_paused: bool) -> Self {
+		self.body.is_paused = Some(is_paused);
+		self
+	}
+
+	/// Sets the reward's settings
+	#[must_use]
+	pub fn with_settings(mut self, settings: RewardSettings) -> Self {
+		self.body.settings = settings;
+		self
+	}
+}
+
+impl HelixRequest for Request {
+	type Response = [CustomReward; 1];
+	type Extra = ();
+	type Body = Body;
+
+	fn url_with_base(&self, base: &url::Url) -> url::Url {
+		let mut url = helix_url!(base => channel_points / custom_rewards);
+		{
+			let mut query_pairs = url.query_pairs_mut();
+			query_pairs.append_pair("broadcaster_id", &self.broadcaster_id);
+			query_pairs.append_pair("id", &self.id);
+		}
+		url
+	}
+
+	fn http_method(&self) -> HttpMethod {
+		HttpMethod::Patch
+	}
+
+	fn body(&self) -> Option<&Self::Body> {
+		Some(&self.body)
+	}
+
+	fn required_scopes(&self) -> &'static [Scope] {
+		&[Scope::ChannelManageRedemptions]
+	}
+}
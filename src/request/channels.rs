@@ -1,5 +1,10 @@
 //! Channel requests
 
 // Modules
+pub mod ads;
 pub mod commercial;
+pub mod editors;
+pub mod followed;
+pub mod followers;
 pub mod info;
+pub mod modify;
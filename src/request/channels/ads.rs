@@ -0,0 +1,147 @@
+//! Ad schedule request
+
+// Modules
+pub mod snooze;
+
+// Imports
+use crate::{helix_url, request::oauth::Scope, HelixRequest, HttpMethod};
+use std::time::Duration;
+
+/// Ad schedule request
+///
+/// This request uses the `/channels/ads` path
+/// to get information about a channel's ad schedule,
+/// such as when the next ad is scheduled.
+///
+/// # Examples
+/// ```
+/// # use twitch_helix::request::channels::ads::Request;
+/// # use twitch_helix::HelixRequest;
+/// let mut request = Request::new("my-channel-id");
+///
+/// let url = request.url();
+/// assert_eq!(url.host_str(), Some("api.twitch.tv"));
+/// assert_eq!(url.path(), "/helix/channels/ads");
+/// assert_eq!(url.query(), Some("broadcaster_id=my-channel-id"));
+/// ```
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Request {
+	/// Broadcaster ID
+	broadcaster_id: String,
+}
+
+impl Request {
+	/// Creates a new request
+	#[must_use]
+	pub fn new(broadcaster_id: impl Into<String>) -> Self {
+		Self {
+			broadcaster_id: broadcaster_id.into(),
+		}
+	}
+}
+
+impl HelixRequest for Request {
+	type Response = [AdSchedule; 1];
+	type Extra = ();
+	type Body = ();
+
+	fn url_with_base(&self, base: &url::Url) -> url::Url {
+		let mut url = helix_url!(base => channels / ads);
+		url.query_pairs_mut().append_pair("broadcaster_id", &self.broadcaster_id);
+		url
+	}
+
+	fn http_method(&self) -> HttpMethod {
+		HttpMethod::Get
+	}
+
+	fn required_scopes(&self) -> &'static [Scope] {
+		&[Scope::ChannelReadAds]
+	}
+}
+
+/// A channel's ad schedule
+#[derive(PartialEq, Eq, Clone, Debug)]
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct AdSchedule {
+	/// When the next ad is scheduled
+	///
+	/// If no ad is scheduled, or the channel isn't live, this is `None`.
+	#[serde(with = "crate::util::epoch_or_utc_date_time")]
+	pub next_ad_at: Option<chrono::DateTime<chrono::Utc>>,
+
+	/// When the last ad ran
+	///
+	/// If no ad has run, this is `None`.
+	#[serde(with = "crate::util::epoch_or_utc_date_time")]
+	pub last_ad_at: Option<chrono::DateTime<chrono::Utc>>,
+
+	/// Length of the next ad
+	#[serde(with = "crate::util::duration_secs")]
+	pub duration: Duration,
+
+	/// Remaining pre-roll free time
+	#[serde(with = "crate::util::duration_secs")]
+	pub preroll_free_time: Duration,
+
+	/// Number of snoozes available
+	#[serde(with = "crate::util::string_or_int")]
+	pub snooze_count: usize,
+
+	/// When a new snooze will be available
+	#[serde(with = "crate::util::epoch_or_utc_date_time")]
+	pub snooze_refresh_at: Option<chrono::DateTime<chrono::Utc>>,
+}
+
+#[cfg(test)]
+mod test {
+	// Imports
+	use super::*;
+	use crate::response::ResponseData;
+	use chrono::{TimeZone, Utc};
+
+	#[test]
+	fn deserialize_documented() {
+		let response = r#"{
+			"data": [{
+				"next_ad_at" : "2023-08-01T23:08:18+00:00",
+				"last_ad_at" : "2023-08-01T23:08:18+00:00",
+				"duration" : "60",
+				"preroll_free_time" : "90",
+				"snooze_count" : "1",
+				"snooze_refresh_at" : "2023-08-01T23:08:18+00:00"
+			}]
+		}"#;
+
+		let response: ResponseData<[AdSchedule; 1]> = serde_json::from_str(response).expect("Unable to parse ad schedule");
+		let [schedule] = response.data;
+		let date_time = Utc.timestamp_opt(1_690_931_298, 0).single();
+		assert_eq!(schedule.next_ad_at, date_time);
+		assert_eq!(schedule.last_ad_at, date_time);
+		assert_eq!(schedule.duration, Duration::from_secs(60));
+		assert_eq!(schedule.preroll_free_time, Duration::from_secs(90));
+		assert_eq!(schedule.snooze_count, 1);
+		assert_eq!(schedule.snooze_refresh_at, date_time);
+	}
+
+	#[test]
+	fn deserialize_epoch() {
+		let response = r#"{
+			"data": [{
+				"next_ad_at": 1690931298,
+				"last_ad_at": 0,
+				"duration": 60,
+				"preroll_free_time": 90,
+				"snooze_count": 3,
+				"snooze_refresh_at": 1690931298
+			}]
+		}"#;
+
+		let response: ResponseData<[AdSchedule; 1]> = serde_json::from_str(response).expect("Unable to parse ad schedule");
+		let [schedule] = response.data;
+		assert_eq!(schedule.next_ad_at, Utc.timestamp_opt(1_690_931_298, 0).single());
+		assert_eq!(schedule.last_ad_at, None);
+		assert_eq!(schedule.duration, Duration::from_secs(60));
+		assert_eq!(schedule.snooze_count, 3);
+	}
+}
@@ -0,0 +1,73 @@
+//! Snooze next ad request
+
+// Imports
+use crate::{helix_url, request::oauth::Scope, HelixRequest, HttpMethod};
+
+/// Snooze next ad request
+///
+/// This request uses the `/channels/ads/schedule/snooze` path
+/// to push back a channel's next scheduled ad by 5 minutes.
+///
+/// # Examples
+/// ```
+/// # use twitch_helix::request::channels::ads::snooze::Request;
+/// # use twitch_helix::HelixRequest;
+/// let mut request = Request::new("my-channel-id");
+///
+/// let url = request.url();
+/// assert_eq!(url.host_str(), Some("api.twitch.tv"));
+/// assert_eq!(url.path(), "/helix/channels/ads/schedule/snooze");
+/// assert_eq!(url.query(), Some("broadcaster_id=my-channel-id"));
+/// ```
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Request {
+	/// Broadcaster ID
+	broadcaster_id: String,
+}
+
+impl Request {
+	/// Creates a new request
+	#[must_use]
+	pub fn new(broadcaster_id: impl Into<String>) -> Self {
+		Self {
+			broadcaster_id: broadcaster_id.into(),
+		}
+	}
+}
+
+impl HelixRequest for Request {
+	type Response = [Response; 1];
+	type Extra = ();
+	type Body = ();
+
+	fn url_with_base(&self, base: &url::Url) -> url::Url {
+		let mut url = helix_url!(base => channels / ads / schedule / snooze);
+		url.query_pairs_mut().append_pair("broadcaster_id", &self.broadcaster_id);
+		url
+	}
+
+	fn http_method(&self) -> HttpMethod {
+		HttpMethod::Post
+	}
+
+	fn required_scopes(&self) -> &'static [Scope] {
+		&[Scope::ChannelManageAds]
+	}
+}
+
+/// The response from the server
+#[derive(PartialEq, Eq, Clone, Debug)]
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct Response {
+	/// Number of snoozes left
+	#[serde(with = "crate::util::string_or_int")]
+	pub snooze_count: usize,
+
+	/// When a new snooze will be available
+	#[serde(with = "crate::util::epoch_or_utc_date_time")]
+	pub snooze_refresh_at: Option<chrono::DateTime<chrono::Utc>>,
+
+	/// When the next ad is now scheduled
+	#[serde(with = "crate::util::epoch_or_utc_date_time")]
+	pub next_ad_at: Option<chrono::DateTime<chrono::Utc>>,
+}
@@ -1,7 +1,8 @@
 //! Channel start commercial request
 
 // Imports
-use crate::{helix_url, HelixRequest, HttpMethod};
+use crate::{helix_url, request::oauth::Scope, HelixRequest, HttpMethod};
+use std::{convert::TryFrom, fmt, str::FromStr, time::Duration};
 
 /// Channel start commercial request
 ///
@@ -30,7 +31,25 @@ pub struct Request {
 }
 
 /// A commercial length
+///
+/// Lengths may be created from any number of seconds, either rejecting
+/// those that aren't exactly a length, with [`TryFrom`], or rounding them
+/// to the nearest length, with [`Length::from_secs_rounded`].
+///
+/// # Examples
+/// ```
+/// # use twitch_helix::request::channels::commercial::Length;
+/// # use std::{convert::TryFrom, time::Duration};
+/// assert_eq!(Length::try_from(60), Ok(Length::Seconds60));
+/// assert!(Length::try_from(61).is_err());
+/// assert_eq!("90".parse(), Ok(Length::Seconds90));
+/// assert_eq!(Length::from_secs_rounded(100), Length::Seconds90);
+/// assert_eq!(Length::from_secs_rounded(1000), Length::Seconds180);
+/// assert_eq!(Duration::from(Length::Seconds120), Duration::from_secs(120));
+/// ```
 #[derive(PartialEq, Eq, Clone, Copy, Debug)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(try_from = "u32", into = "u32")]
 pub enum Length {
 	/// 30 seconds
 	Seconds30,
@@ -51,7 +70,30 @@ pub enum Length {
 	Seconds180,
 }
 
+/// Error type for converting to a [`Length`]
+#[derive(PartialEq, Eq, Clone, Debug)]
+#[derive(thiserror::Error)]
+pub enum LengthError {
+	/// Not a commercial length
+	#[error("{0:?} isn't a commercial length")]
+	Invalid(Duration),
+
+	/// Unable to parse number of seconds
+	#[error("Unable to parse number of seconds")]
+	Parse(#[source] std::num::ParseIntError),
+}
+
 impl Length {
+	/// All lengths, from shortest to longest
+	pub const ALL: [Self; 6] = [
+		Self::Seconds30,
+		Self::Seconds60,
+		Self::Seconds90,
+		Self::Seconds120,
+		Self::Seconds150,
+		Self::Seconds180,
+	];
+
 	/// Returns the number of seconds of this length
 	#[must_use]
 	pub const fn secs(self) -> usize {
@@ -64,6 +106,89 @@ impl Length {
 			Self::Seconds180 => 180,
 		}
 	}
+
+	/// Returns the number of seconds of this length, as a `u32`
+	const fn secs_u32(self) -> u32 {
+		match self {
+			Self::Seconds30 => 30,
+			Self::Seconds60 => 60,
+			Self::Seconds90 => 90,
+			Self::Seconds120 => 120,
+			Self::Seconds150 => 150,
+			Self::Seconds180 => 180,
+		}
+	}
+
+	/// Returns the length nearest to a number of seconds
+	///
+	/// Numbers of seconds halfway between two lengths round up.
+	#[must_use]
+	pub fn from_secs_rounded(secs: u32) -> Self {
+		// Note: Lengths are 30 seconds apart, so the first length
+		//       less than 15 seconds below `secs` is the nearest.
+		Self::ALL
+			.iter()
+			.copied()
+			.find(|length| secs < length.secs_u32().saturating_add(15))
+			.unwrap_or(Self::Seconds180)
+	}
+
+	/// Returns the length nearest to a duration
+	///
+	/// See [`Length::from_secs_rounded`] for more information.
+	#[must_use]
+	pub fn from_duration_rounded(duration: Duration) -> Self {
+		Self::from_secs_rounded(u32::try_from(duration.as_secs()).unwrap_or(u32::MAX))
+	}
+}
+
+impl TryFrom<u32> for Length {
+	type Error = LengthError;
+
+	fn try_from(secs: u32) -> Result<Self, Self::Error> {
+		Self::ALL
+			.iter()
+			.copied()
+			.find(|length| length.secs_u32() == secs)
+			.ok_or_else(|| LengthError::Invalid(Duration::from_secs(u64::from(secs))))
+	}
+}
+
+impl TryFrom<Duration> for Length {
+	type Error = LengthError;
+
+	fn try_from(duration: Duration) -> Result<Self, Self::Error> {
+		match u32::try_from(duration.as_secs()) {
+			Ok(secs) if duration.subsec_nanos() == 0 => Self::try_from(secs),
+			_ => Err(LengthError::Invalid(duration)),
+		}
+	}
+}
+
+impl FromStr for Length {
+	type Err = LengthError;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		s.parse::<u32>().map_err(LengthError::Parse).and_then(Self::try_from)
+	}
+}
+
+impl From<Length> for u32 {
+	fn from(length: Length) -> Self {
+		length.secs_u32()
+	}
+}
+
+impl From<Length> for Duration {
+	fn from(length: Length) -> Self {
+		Self::from_secs(u64::from(u32::from(length)))
+	}
+}
+
+impl fmt::Display for Length {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(f, "{}", self.secs())
+	}
 }
 
 impl Request {
@@ -79,9 +204,11 @@ impl Request {
 
 impl HelixRequest for Request {
 	type Response = [Response; 1];
+	type Extra = ();
+	type Body = ();
 
-	fn url(&self) -> url::Url {
-		let mut url = helix_url!(channels / commercial);
+	fn url_with_base(&self, base: &url::Url) -> url::Url {
+		let mut url = helix_url!(base => channels / commercial);
 		{
 			let mut query_pairs = url.query_pairs_mut();
 			query_pairs.append_pair("broadcaster_id", &self.broadcaster_id);
@@ -93,6 +220,10 @@ impl HelixRequest for Request {
 	fn http_method(&self) -> HttpMethod {
 		HttpMethod::Post
 	}
+
+	fn required_scopes(&self) -> &'static [Scope] {
+		&[Scope::ChannelEditCommercial]
+	}
 }
 
 /// The response from the server
@@ -100,9 +231,58 @@ impl HelixRequest for Request {
 #[derive(serde::Serialize, serde::Deserialize)]
 pub struct Response {
 	/// Length of the commercial triggered
-	pub length: usize,
+	///
+	/// Twitch may shorten the commercial from the length requested,
+	/// so this isn't necessarily a [`Length`].
+	#[serde(with = "crate::util::duration_secs")]
+	pub length: Duration,
 
-	/// Seconds until the next commercial can be
+	/// Message
+	///
+	/// Explains why the commercial length was changed, if it was.
+	#[serde(default)]
+	pub message: String,
+
+	/// Time until the next commercial can be
 	/// served on this channel
-	pub retry_after: usize,
+	#[serde(with = "crate::util::duration_secs")]
+	pub retry_after: Duration,
+}
+
+#[cfg(test)]
+mod test {
+	// Imports
+	use super::*;
+
+	#[test]
+	fn from_secs_rounded() {
+		assert_eq!(Length::from_secs_rounded(0), Length::Seconds30);
+		assert_eq!(Length::from_secs_rounded(44), Length::Seconds30);
+		assert_eq!(Length::from_secs_rounded(45), Length::Seconds60);
+		assert_eq!(Length::from_secs_rounded(165), Length::Seconds180);
+		assert_eq!(Length::from_secs_rounded(u32::MAX), Length::Seconds180);
+	}
+
+	#[test]
+	fn try_from_duration() {
+		assert_eq!(Length::try_from(Duration::from_secs(150)), Ok(Length::Seconds150));
+		assert_eq!(
+			Length::try_from(Duration::from_millis(150_500)),
+			Err(LengthError::Invalid(Duration::from_millis(150_500)))
+		);
+	}
+
+	#[test]
+	fn deserialize() {
+		let response = r#"{ "length": 60, "message": "", "retry_after": 480 }"#;
+		let response: Response = serde_json::from_str(response).expect("Unable to parse response");
+		assert_eq!(response.length, Duration::from_secs(60));
+		assert_eq!(response.retry_after, Duration::from_secs(480));
+
+		let response = r#"{ "length": 45, "message": "Commercial was shortened", "retry_after": 480 }"#;
+		let response: Response = serde_json::from_str(response).expect("Unable to parse shortened response");
+		assert_eq!(response.length, Duration::from_secs(45));
+
+		assert!(serde_json::from_str::<Length>("45").is_err());
+	}
 }
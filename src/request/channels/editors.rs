@@ -0,0 +1,71 @@
+//! Channel editors request
+
+// Imports
+use crate::{helix_url, request::oauth::Scope, HelixRequest, HttpMethod};
+
+/// Channel editors request
+///
+/// This request uses the `/channels/editors` path
+/// to get a list of the users that are editors
+/// of a channel.
+///
+/// # Examples
+/// ```
+/// # use twitch_helix::request::channels::editors::Request;
+/// # use twitch_helix::HelixRequest;
+/// let mut request = Request::new("my-channel-id");
+///
+/// let url = request.url();
+/// assert_eq!(url.host_str(), Some("api.twitch.tv"));
+/// assert_eq!(url.path(), "/helix/channels/editors");
+/// assert_eq!(url.query(), Some("broadcaster_id=my-channel-id"));
+/// ```
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Request {
+	/// Broadcaster ID
+	broadcaster_id: String,
+}
+
+impl Request {
+	/// Creates a new request
+	#[must_use]
+	pub fn new(broadcaster_id: impl Into<String>) -> Self {
+		Self {
+			broadcaster_id: broadcaster_id.into(),
+		}
+	}
+}
+
+impl HelixRequest for Request {
+	type Response = Vec<Editor>;
+	type Extra = ();
+	type Body = ();
+
+	fn url_with_base(&self, base: &url::Url) -> url::Url {
+		let mut url = helix_url!(base => channels / editors);
+		url.query_pairs_mut().append_pair("broadcaster_id", &self.broadcaster_id);
+		url
+	}
+
+	fn http_method(&self) -> HttpMethod {
+		HttpMethod::Get
+	}
+
+	fn required_scopes(&self) -> &'static [Scope] {
+		&[Scope::ChannelReadEditors]
+	}
+}
+
+/// Each editor in the output data
+#[derive(PartialEq, Eq, Clone, Debug)]
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct Editor {
+	/// User id
+	pub user_id: String,
+
+	/// User display name
+	pub user_name: String,
+
+	/// When the user became an editor
+	pub created_at: chrono::DateTime<chrono::Utc>,
+}
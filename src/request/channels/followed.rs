@@ -0,0 +1,149 @@
+//! Followed channels request
+
+// Imports
+use super::followers::Total;
+use crate::{helix_url, request::oauth::Scope, HelixRequest, HttpMethod, PaginatedRequest};
+
+/// Followed channels request
+///
+/// This request uses the `/channels/followed` path
+/// to get a list of the channels a user follows.
+///
+/// # Examples
+/// Simple request:
+/// ```
+/// # use twitch_helix::request::channels::followed::Request;
+/// # use twitch_helix::HelixRequest;
+/// let mut request = Request::new("my-user-id");
+///
+/// let url = request.url();
+/// assert_eq!(url.host_str(), Some("api.twitch.tv"));
+/// assert_eq!(url.path(), "/helix/channels/followed");
+/// assert_eq!(url.query(), Some("user_id=my-user-id"));
+/// ```
+///
+/// Using every argument:
+/// ```
+/// # use twitch_helix::request::channels::followed::Request;
+/// # use twitch_helix::HelixRequest;
+/// let mut request = Request::new("my-user-id")
+///   .with_broadcaster_id("my-channel-id")
+///   .with_first(100)
+///   .with_after("my-cursor".to_string());
+///
+/// let url = request.url();
+/// assert_eq!(url.host_str(), Some("api.twitch.tv"));
+/// assert_eq!(url.path(), "/helix/channels/followed");
+/// assert_eq!(url.query(), Some("user_id=my-user-id&broadcaster_id=my-channel-id&first=100&after=my-cursor"));
+/// ```
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Request {
+	/// User id
+	user_id: String,
+
+	/// Broadcaster ID, to check if the user follows them
+	broadcaster_id: Option<String>,
+
+	/// Maximum number of objects to return
+	first: Option<usize>,
+
+	/// Cursor for forward pagination
+	after: Option<String>,
+}
+
+impl Request {
+	/// Creates a new request
+	#[must_use]
+	pub fn new(user_id: impl Into<String>) -> Self {
+		Self {
+			user_id: user_id.into(),
+			broadcaster_id: None,
+			first: None,
+			after: None,
+		}
+	}
+
+	/// Sets the broadcaster id, to check if the user follows them
+	///
+	/// If they do, only they are returned, else nothing is returned.
+	#[must_use]
+	pub fn with_broadcaster_id(self, broadcaster_id: impl Into<String>) -> Self {
+		Self {
+			broadcaster_id: Some(broadcaster_id.into()),
+			..self
+		}
+	}
+
+	/// Sets the maximum number of objects to return
+	#[must_use]
+	pub fn with_first(self, first: usize) -> Self {
+		Self { first: Some(first), ..self }
+	}
+
+	/// Sets the cursor for forward pagination
+	#[must_use]
+	pub fn with_after(self, after: String) -> Self {
+		Self { after: Some(after), ..self }
+	}
+}
+
+impl HelixRequest for Request {
+	type Response = Vec<FollowedChannel>;
+	type Extra = Total;
+	type Body = ();
+
+	fn url_with_base(&self, base: &url::Url) -> url::Url {
+		// Append all our arguments if they exist
+		let mut url = helix_url!(base => channels / followed);
+
+		{
+			let mut query_pairs = url.query_pairs_mut();
+			query_pairs.append_pair("user_id", &self.user_id);
+			if let Some(broadcaster_id) = &self.broadcaster_id {
+				query_pairs.append_pair("broadcaster_id", broadcaster_id);
+			}
+			if let Some(first) = &self.first {
+				query_pairs.append_pair("first", &first.to_string());
+			}
+			if let Some(after) = &self.after {
+				query_pairs.append_pair("after", after);
+			}
+		}
+		url
+	}
+
+	fn http_method(&self) -> HttpMethod {
+		HttpMethod::Get
+	}
+
+	fn required_scopes(&self) -> &'static [Scope] {
+		&[Scope::UserReadFollows]
+	}
+}
+
+impl PaginatedRequest for Request {
+	fn set_after(&mut self, after: Option<String>) {
+		self.after = after;
+	}
+
+	fn set_first(&mut self, first: Option<usize>) {
+		self.first = first;
+	}
+}
+
+/// Each followed channel in the output data
+#[derive(PartialEq, Eq, Clone, Debug)]
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct FollowedChannel {
+	/// Broadcaster id
+	pub broadcaster_id: String,
+
+	/// Broadcaster login name
+	pub broadcaster_login: String,
+
+	/// Broadcaster display name
+	pub broadcaster_name: String,
+
+	/// When the user followed the channel
+	pub followed_at: chrono::DateTime<chrono::Utc>,
+}
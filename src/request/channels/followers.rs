@@ -0,0 +1,194 @@
+//! Channel followers request
+
+// Imports
+use crate::{helix_url, HelixRequest, HttpMethod, PaginatedRequest};
+
+/// Channel followers request
+///
+/// This request uses the `/channels/followers` path
+/// to get a list of the users that follow a channel.
+///
+/// Follower information is only returned if the token belongs to
+/// the broadcaster or one of it's moderators, and has the
+/// [`ModeratorReadFollowers`](crate::request::oauth::Scope::ModeratorReadFollowers)
+/// scope. Otherwise, only the total number of followers is returned.
+///
+/// # Examples
+/// Simple request:
+/// ```
+/// # use twitch_helix::request::channels::followers::Request;
+/// # use twitch_helix::HelixRequest;
+/// let mut request = Request::new("my-channel-id");
+///
+/// let url = request.url();
+/// assert_eq!(url.host_str(), Some("api.twitch.tv"));
+/// assert_eq!(url.path(), "/helix/channels/followers");
+/// assert_eq!(url.query(), Some("broadcaster_id=my-channel-id"));
+/// ```
+///
+/// Using every argument:
+/// ```
+/// # use twitch_helix::request::channels::followers::Request;
+/// # use twitch_helix::HelixRequest;
+/// let mut request = Request::new("my-channel-id")
+///   .with_user_id("my-user-id")
+///   .with_first(100)
+///   .with_after("my-cursor".to_string());
+///
+/// let url = request.url();
+/// assert_eq!(url.host_str(), Some("api.twitch.tv"));
+/// assert_eq!(url.path(), "/helix/channels/followers");
+/// assert_eq!(url.query(), Some("broadcaster_id=my-channel-id&user_id=my-user-id&first=100&after=my-cursor"));
+/// ```
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Request {
+	/// Broadcaster ID
+	broadcaster_id: String,
+
+	/// User id, to check if they follow the channel
+	user_id: Option<String>,
+
+	/// Maximum number of objects to return
+	first: Option<usize>,
+
+	/// Cursor for forward pagination
+	after: Option<String>,
+}
+
+impl Request {
+	/// Creates a new request
+	#[must_use]
+	pub fn new(broadcaster_id: impl Into<String>) -> Self {
+		Self {
+			broadcaster_id: broadcaster_id.into(),
+			user_id: None,
+			first: None,
+			after: None,
+		}
+	}
+
+	/// Sets the user id, to check if they follow the channel
+	///
+	/// If they do, only they are returned, else nothing is returned.
+	#[must_use]
+	pub fn with_user_id(self, user_id: impl Into<String>) -> Self {
+		Self {
+			user_id: Some(user_id.into()),
+			..self
+		}
+	}
+
+	/// Sets the maximum number of objects to return
+	#[must_use]
+	pub fn with_first(self, first: usize) -> Self {
+		Self { first: Some(first), ..self }
+	}
+
+	/// Sets the cursor for forward pagination
+	#[must_use]
+	pub fn with_after(self, after: String) -> Self {
+		Self { after: Some(after), ..self }
+	}
+}
+
+impl HelixRequest for Request {
+	type Response = Vec<Follower>;
+	type Extra = Total;
+	type Body = ();
+
+	fn url_with_base(&self, base: &url::Url) -> url::Url {
+		// Append all our arguments if they exist
+		let mut url = helix_url!(base => channels / followers);
+
+		{
+			let mut query_pairs = url.query_pairs_mut();
+			query_pairs.append_pair("broadcaster_id", &self.broadcaster_id);
+			if let Some(user_id) = &self.user_id {
+				query_pairs.append_pair("user_id", user_id);
+			}
+			if let Some(first) = &self.first {
+				query_pairs.append_pair("first", &first.to_string());
+			}
+			if let Some(after) = &self.after {
+				query_pairs.append_pair("after", after);
+			}
+		}
+		url
+	}
+
+	fn http_method(&self) -> HttpMethod {
+		HttpMethod::Get
+	}
+}
+
+impl PaginatedRequest for Request {
+	fn set_after(&mut self, after: Option<String>) {
+		self.after = after;
+	}
+
+	fn set_first(&mut self, first: Option<usize>) {
+		self.first = first;
+	}
+}
+
+/// Each follower in the output data
+#[derive(PartialEq, Eq, Clone, Debug)]
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct Follower {
+	/// User id
+	pub user_id: String,
+
+	/// User login name
+	pub user_login: String,
+
+	/// User display name
+	pub user_name: String,
+
+	/// When the user followed the channel
+	pub followed_at: chrono::DateTime<chrono::Utc>,
+}
+
+/// Total number of results, returned alongside the data
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct Total {
+	/// Total
+	pub total: usize,
+}
+
+#[cfg(test)]
+mod test {
+	// Imports
+	use super::*;
+	use crate::response::ResponseData;
+
+	#[test]
+	fn deserialize() {
+		let response = r#"{
+			"total": 8,
+			"data": [
+				{ "user_id": "11111", "user_name": "UserDisplayName", "user_login": "userloginname", "followed_at": "2022-05-24T22:22:08Z" }
+			],
+			"pagination": { "cursor": "eyJiIjpudWxsLCJhIjp7Ik9mZnNldCI6NX19" }
+		}"#;
+
+		let response: ResponseData<Vec<Follower>, Total> = serde_json::from_str(response).expect("Unable to parse followers");
+		assert_eq!(response.extra.total, 8);
+		assert_eq!(response.data[0].user_login, "userloginname");
+		assert_eq!(
+			response.data[0].followed_at,
+			"2022-05-24T22:22:08Z"
+				.parse::<chrono::DateTime<chrono::Utc>>()
+				.expect("Invalid date-time")
+		);
+	}
+
+	#[test]
+	fn deserialize_total_only() {
+		let response = r#"{ "total": 8, "data": [], "pagination": {} }"#;
+
+		let response: ResponseData<Vec<Follower>, Total> = serde_json::from_str(response).expect("Unable to parse followers");
+		assert_eq!(response.extra.total, 8);
+		assert!(response.data.is_empty());
+	}
+}
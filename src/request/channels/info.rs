@@ -1,15 +1,20 @@
 //! Channel info request
 
 // Imports
+use super::modify::ContentClassificationLabel;
 use crate::{helix_url, HelixRequest, HttpMethod};
 
 /// Channel info request
 ///
 /// This request uses the `/channels` path
-/// to get information about a channel
-/// given their broadcaster id.
+/// to get information about channels
+/// given their broadcaster ids.
+///
+/// Up to [`MAX_BROADCASTER_IDS`] channels may be requested at once.
+/// To request more, see [`Client::request_channels_info`](crate::Client::request_channels_info).
 ///
 /// # Examples
+/// Single channel:
 /// ```
 /// # use twitch_helix::request::channel::info::Request;
 /// # use twitch_helix::HelixRequest;
@@ -21,30 +26,69 @@ use crate::{helix_url, HelixRequest, HttpMethod};
 /// assert_eq!(url.query(), Some("broadcaster_id=my-channel-id"));
 /// ```
 ///
+/// Multiple channels:
+/// ```
+/// # use twitch_helix::request::channel::info::Request;
+/// # use twitch_helix::HelixRequest;
+/// let mut request = Request::from_ids(vec!["my-channel-id", "my-other-channel-id"]);
+///
+/// let url = request.url();
+/// assert_eq!(url.host_str(), Some("api.twitch.tv"));
+/// assert_eq!(url.path(), "/helix/channels");
+/// assert_eq!(url.query(), Some("broadcaster_id=my-channel-id&broadcaster_id=my-other-channel-id"));
+/// ```
 #[derive(PartialEq, Eq, Clone, Debug)]
 pub struct Request {
-	/// Broadcaster ID
-	broadcaster_id: String,
+	/// Broadcaster IDs
+	broadcaster_ids: Vec<String>,
 }
 
+/// Maximum number of broadcaster ids per request
+pub const MAX_BROADCASTER_IDS: usize = 100;
+
 impl Request {
 	/// Creates a new request
 	#[must_use]
 	pub fn new(broadcaster_id: impl Into<String>) -> Self {
 		Self {
-			broadcaster_id: broadcaster_id.into(),
+			broadcaster_ids: vec![broadcaster_id.into()],
 		}
 	}
 
+	/// Creates a new request for several channels
+	#[must_use]
+	pub fn from_ids<I>(broadcaster_ids: I) -> Self
+	where
+		I: IntoIterator,
+		I::Item: Into<String>,
+	{
+		Self {
+			broadcaster_ids: broadcaster_ids.into_iter().map(Into::into).collect(),
+		}
+	}
+
+	/// Adds a broadcaster id to request
+	#[must_use]
+	pub fn with_broadcaster_id(mut self, broadcaster_id: impl Into<String>) -> Self {
+		self.broadcaster_ids.push(broadcaster_id.into());
+		self
+	}
+
+	/// Returns all broadcaster ids requested
+	#[must_use]
+	pub fn broadcaster_ids(&self) -> &[String] {
+		&self.broadcaster_ids
+	}
+
 	/// Finds the exact channel requested given the response
 	///
-	/// Attempts to find an exact match in the `display_name`
-	/// field of the channel, without considering case.
+	/// Attempts to find a channel whose id matches any of
+	/// the requested ids, without considering case.
 	#[must_use]
 	pub fn channel(&self, channels: Vec<Channel>) -> Option<Channel> {
 		// Check every channel in the response
 		for channel in channels {
-			if unicase::eq(&self.broadcaster_id, &channel.broadcast_id) {
+			if self.is_requested(&channel) {
 				return Some(channel);
 			}
 		}
@@ -60,7 +104,7 @@ impl Request {
 	pub fn channel_ref<'a>(&self, channels: &'a [Channel]) -> Option<&'a Channel> {
 		// Check every channel in the response
 		for channel in channels {
-			if unicase::eq(&self.broadcaster_id, &channel.broadcast_id) {
+			if self.is_requested(channel) {
 				return Some(channel);
 			}
 		}
@@ -68,14 +112,28 @@ impl Request {
 		// If we get here, no channel was found
 		None
 	}
+
+	/// Returns if a channel was requested
+	fn is_requested(&self, channel: &Channel) -> bool {
+		self.broadcaster_ids
+			.iter()
+			.any(|broadcaster_id| unicase::eq(broadcaster_id, &channel.broadcaster_id))
+	}
 }
 
 impl HelixRequest for Request {
 	type Response = Vec<Channel>;
-
-	fn url(&self) -> url::Url {
-		let mut url = helix_url!(channels);
-		url.query_pairs_mut().append_pair("broadcaster_id", &self.broadcaster_id);
+	type Extra = ();
+	type Body = ();
+
+	fn url_with_base(&self, base: &url::Url) -> url::Url {
+		let mut url = helix_url!(base => channels);
+		{
+			let mut query_pairs = url.query_pairs_mut();
+			for broadcaster_id in &self.broadcaster_ids {
+				query_pairs.append_pair("broadcaster_id", broadcaster_id);
+			}
+		}
 		url
 	}
 
@@ -88,21 +146,80 @@ impl HelixRequest for Request {
 #[derive(PartialEq, Eq, Clone, Debug)]
 #[derive(serde::Serialize, serde::Deserialize)]
 pub struct Channel {
-	/// Channel streaming status
-	pub status: String,
-
 	/// Broadcaster id
-	pub broadcast_id: String,
+	pub broadcaster_id: String,
+
+	/// Broadcaster login name
+	pub broadcaster_login: String,
+
+	/// Broadcaster display name
+	pub broadcaster_name: String,
+
+	/// Broadcaster language
+	pub broadcaster_language: String,
 
 	/// Game id
 	pub game_id: String,
 
-	/// Broadcaster_language,
-	pub broadcaster_language: String,
+	/// Game name
+	pub game_name: String,
 
 	/// Title
 	pub title: String,
 
-	/// Description
-	pub description: String,
+	/// Broadcast delay, in seconds
+	pub delay: u32,
+
+	/// Tags
+	pub tags: Vec<String>,
+
+	/// Content classification labels
+	pub content_classification_labels: Vec<ContentClassificationLabel>,
+
+	/// If the channel has branded content
+	pub is_branded_content: bool,
+}
+
+#[cfg(test)]
+mod test {
+	// Imports
+	use super::*;
+	use crate::response::ResponseData;
+
+	#[test]
+	fn deserialize() {
+		let response: ResponseData<Vec<Channel>> =
+			serde_json::from_str(include_str!("../../../test-data/channels.json")).expect("Unable to parse channels");
+		assert_eq!(response.data.len(), 2);
+
+		let channel = &response.data[0];
+		assert_eq!(channel.broadcaster_id, "141981764");
+		assert_eq!(channel.broadcaster_login, "twitchdev");
+		assert_eq!(channel.broadcaster_name, "TwitchDev");
+		assert_eq!(channel.game_name, "Science & Technology");
+		assert_eq!(channel.delay, 0);
+		assert_eq!(channel.tags, vec!["DevsInTheKnow"]);
+		assert_eq!(
+			channel.content_classification_labels,
+			vec![
+				ContentClassificationLabel::Gambling,
+				ContentClassificationLabel::DrugsIntoxication,
+				ContentClassificationLabel::MatureGame
+			]
+		);
+		assert!(!channel.is_branded_content);
+	}
+
+	#[test]
+	fn channel() {
+		let response: ResponseData<Vec<Channel>> =
+			serde_json::from_str(include_str!("../../../test-data/channels.json")).expect("Unable to parse channels");
+
+		let request = Request::new("12826");
+		assert_eq!(
+			request.channel_ref(&response.data).map(|channel| channel.broadcaster_login.as_str()),
+			Some("twitch")
+		);
+		assert_eq!(Request::new("0").channel(response.data), None);
+	}
 }
@@ -0,0 +1,373 @@
+//! Modify channel information request
+
+// Imports
+use crate::{helix_url, request::oauth::Scope, HelixRequest, HttpMethod};
+
+/// Maximum number of characters in a title
+pub const MAX_TITLE_LEN: usize = 140;
+
+/// Maximum number of tags
+pub const MAX_TAGS: usize = 10;
+
+/// Maximum number of characters in a tag
+pub const MAX_TAG_LEN: usize = 25;
+
+/// Modify channel information request
+///
+/// This request uses the `/channels` path
+/// to update a channel's information.
+///
+/// Only the fields set are sent, with the rest
+/// being left as they are.
+///
+/// Twitch returns no data for this request.
+///
+/// # Examples
+/// ```
+/// # use twitch_helix::request::channels::modify::{Request, ValidationError};
+/// # use twitch_helix::HelixRequest;
+/// # fn main() -> Result<(), ValidationError> {
+/// let mut request = Request::new("my-channel-id")
+///   .with_title("My title")?
+///   .with_game_id("my-game-id")
+///   .with_tags(vec!["English".to_owned(), "Speedrun".to_owned()])?;
+///
+/// let url = request.url();
+/// assert_eq!(url.host_str(), Some("api.twitch.tv"));
+/// assert_eq!(url.path(), "/helix/channels");
+/// assert_eq!(url.query(), Some("broadcaster_id=my-channel-id"));
+///
+/// let body = serde_json::to_value(request.body()).expect("Unable to serialize body");
+/// assert_eq!(body["title"], "My title");
+/// assert_eq!(body["game_id"], "my-game-id");
+/// assert_eq!(body.get("delay"), None);
+/// # Ok(())
+/// # }
+/// ```
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Request {
+	/// Broadcaster ID
+	broadcaster_id: String,
+
+	/// Body
+	body: Body,
+}
+
+/// Request body
+#[derive(PartialEq, Eq, Clone, Default, Debug)]
+#[derive(serde::Serialize)]
+pub struct Body {
+	/// Game id
+	#[serde(skip_serializing_if = "Option::is_none")]
+	game_id: Option<String>,
+
+	/// Broadcaster language
+	#[serde(skip_serializing_if = "Option::is_none")]
+	broadcaster_language: Option<String>,
+
+	/// Title
+	#[serde(skip_serializing_if = "Option::is_none")]
+	title: Option<String>,
+
+	/// Broadcast delay, in seconds
+	#[serde(skip_serializing_if = "Option::is_none")]
+	delay: Option<u32>,
+
+	/// Tags
+	#[serde(skip_serializing_if = "Option::is_none")]
+	tags: Option<Vec<String>>,
+
+	/// Content classification labels
+	#[serde(skip_serializing_if = "Option::is_none")]
+	content_classification_labels: Option<Vec<ContentClassificationLabelState>>,
+
+	/// If the channel has branded content
+	#[serde(skip_serializing_if = "Option::is_none")]
+	is_branded_content: Option<bool>,
+}
+
+/// A content classification label
+///
+/// Any label not known by this library is kept as [`ContentClassificationLabel::Unknown`].
+#[derive(PartialEq, Eq, Clone, Hash, Debug)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(from = "String", into = "String")]
+pub enum ContentClassificationLabel {
+	/// Politics and sensitive social issues
+	DebatedSocialIssuesAndPolitics,
+
+	/// Drugs, intoxication, or excessive tobacco use
+	DrugsIntoxication,
+
+	/// Sexual themes
+	SexualThemes,
+
+	/// Violent and graphic depictions
+	ViolentGraphic,
+
+	/// Gambling
+	Gambling,
+
+	/// Significant profanity or vulgarity
+	ProfanityVulgarity,
+
+	/// Mature-rated game
+	///
+	/// This label is set by the game, and may not be changed.
+	MatureGame,
+
+	/// Unknown label
+	Unknown(String),
+}
+
+impl ContentClassificationLabel {
+	/// Returns the id of this label
+	#[must_use]
+	pub fn as_str(&self) -> &str {
+		match self {
+			Self::DebatedSocialIssuesAndPolitics => "DebatedSocialIssuesAndPolitics",
+			Self::DrugsIntoxication => "DrugsIntoxication",
+			Self::SexualThemes => "SexualThemes",
+			Self::ViolentGraphic => "ViolentGraphic",
+			Self::Gambling => "Gambling",
+			Self::ProfanityVulgarity => "ProfanityVulgarity",
+			Self::MatureGame => "MatureGame",
+			Self::Unknown(id) => id,
+		}
+	}
+}
+
+impl From<String> for ContentClassificationLabel {
+	fn from(id: String) -> Self {
+		match id.as_str() {
+			"DebatedSocialIssuesAndPolitics" => Self::DebatedSocialIssuesAndPolitics,
+			"DrugsIntoxication" => Self::DrugsIntoxication,
+			"SexualThemes" => Self::SexualThemes,
+			"ViolentGraphic" => Self::ViolentGraphic,
+			"Gambling" => Self::Gambling,
+			"ProfanityVulgarity" => Self::ProfanityVulgarity,
+			"MatureGame" => Self::MatureGame,
+			_ => Self::Unknown(id),
+		}
+	}
+}
+
+impl From<ContentClassificationLabel> for String {
+	fn from(label: ContentClassificationLabel) -> Self {
+		match label {
+			ContentClassificationLabel::Unknown(id) => id,
+			label => label.as_str().to_owned(),
+		}
+	}
+}
+
+/// A content classification label, and if it's enabled
+#[derive(PartialEq, Eq, Clone, Debug)]
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct ContentClassificationLabelState {
+	/// Label
+	pub id: ContentClassificationLabel,
+
+	/// If enabled
+	pub is_enabled: bool,
+}
+
+/// Error type for validating the request's fields
+#[derive(PartialEq, Eq, Clone, Debug)]
+#[derive(thiserror::Error)]
+pub enum ValidationError {
+	/// Title was empty
+	#[error("Title was empty")]
+	EmptyTitle,
+
+	/// Title was too long
+	#[error("Title was too long ({0} characters)")]
+	TitleTooLong(usize),
+
+	/// Too many tags
+	#[error("Too many tags ({0})")]
+	TooManyTags(usize),
+
+	/// A tag was invalid
+	///
+	/// Tags must be non-empty, alphanumeric and not too long.
+	#[error("Invalid tag {0:?}")]
+	InvalidTag(String),
+
+	/// A label may not be changed
+	#[error("Label {0:?} may not be changed")]
+	ReadOnlyLabel(ContentClassificationLabel),
+}
+
+impl Request {
+	/// Creates a new request, without any fields set
+	#[must_use]
+	pub fn new(broadcaster_id: impl Into<String>) -> Self {
+		Self {
+			broadcaster_id: broadcaster_id.into(),
+			body: Body::default(),
+		}
+	}
+
+	/// Sets the game id
+	///
+	/// An empty game id, or `"0"`, removes the game.
+	#[must_use]
+	pub fn with_game_id(mut self, game_id: impl Into<String>) -> Self {
+		self.body.game_id = Some(game_id.into());
+		self
+	}
+
+	/// Sets the broadcaster language
+	///
+	/// Must be an ISO 639-1 two-letter language code, or `"other"`.
+	#[must_use]
+	pub fn with_broadcaster_language(mut self, broadcaster_language: impl Into<String>) -> Self {
+		self.body.broadcaster_language = Some(broadcaster_language.into());
+		self
+	}
+
+	/// Sets the title
+	pub fn with_title(mut self, title: impl Into<String>) -> Result<Self, ValidationError> {
+		let title = title.into();
+		let len = title.chars().count();
+		if len == 0 {
+			return Err(ValidationError::EmptyTitle);
+		}
+		if len > MAX_TITLE_LEN {
+			return Err(ValidationError::TitleTooLong(len));
+		}
+
+		self.body.title = Some(title);
+		Ok(self)
+	}
+
+	/// Sets the broadcast delay, in seconds
+	///
+	/// Only available to partners.
+	#[must_use]
+	pub fn with_delay(mut self, delay: u32) -> Self {
+		self.body.delay = Some(delay);
+		self
+	}
+
+	/// Sets the tags
+	///
+	/// An empty list removes all tags.
+	pub fn with_tags(mut self, tags: Vec<String>) -> Result<Self, ValidationError> {
+		if tags.len() > MAX_TAGS {
+			return Err(ValidationError::TooManyTags(tags.len()));
+		}
+		if let Some(tag) = tags
+			.iter()
+			.find(|tag| tag.is_empty() || tag.chars().count() > MAX_TAG_LEN || !tag.chars().all(char::is_alphanumeric))
+		{
+			return Err(ValidationError::InvalidTag(tag.clone()));
+		}
+
+		self.body.tags = Some(tags);
+		Ok(self)
+	}
+
+	/// Sets the content classification labels to enable or disable
+	///
+	/// Labels not given are left as they are.
+	pub fn with_content_classification_labels(mut self, labels: Vec<ContentClassificationLabelState>) -> Result<Self, ValidationError> {
+		if labels.iter().any(|label| label.id == ContentClassificationLabel::MatureGame) {
+			return Err(ValidationError::ReadOnlyLabel(ContentClassificationLabel::MatureGame));
+		}
+
+		self.body.content_classification_labels = Some(labels);
+		Ok(self)
+	}
+
+	/// Sets if the channel has branded content
+	#[must_use]
+	pub fn with_is_branded_content(mut self, is_branded_content: bool) -> Self {
+		self.body.is_branded_content = Some(is_branded_content);
+		self
+	}
+}
+
+impl HelixRequest for Request {
+	type Response = ();
+	type Extra = ();
+	type Body = Body;
+
+	fn url_with_base(&self, base: &url::Url) -> url::Url {
+		let mut url = helix_url!(base => channels);
+		url.query_pairs_mut().append_pair("broadcaster_id", &self.broadcaster_id);
+		url
+	}
+
+	fn http_method(&self) -> HttpMethod {
+		HttpMethod::Patch
+	}
+
+	fn body(&self) -> Option<&Self::Body> {
+		Some(&self.body)
+	}
+
+	fn required_scopes(&self) -> &'static [Scope] {
+		&[Scope::ChannelManageBroadcast]
+	}
+}
+
+#[cfg(test)]
+mod test {
+	// Imports
+	use super::*;
+
+	#[test]
+	fn body() {
+		let request = Request::new("my-channel-id")
+			.with_delay(5)
+			.with_is_branded_content(true)
+			.with_content_classification_labels(vec![ContentClassificationLabelState {
+				id: ContentClassificationLabel::Gambling,
+				is_enabled: true,
+			}])
+			.expect("Invalid labels");
+
+		assert_eq!(
+			serde_json::to_value(request.body()).expect("Unable to serialize body"),
+			serde_json::json!({
+				"delay": 5,
+				"content_classification_labels": [{ "id": "Gambling", "is_enabled": true }],
+				"is_branded_content": true,
+			})
+		);
+	}
+
+	#[test]
+	fn validation() {
+		let request = Request::new("my-channel-id");
+		assert_eq!(request.clone().with_title(""), Err(ValidationError::EmptyTitle));
+		assert_eq!(request.clone().with_title("a".repeat(141)), Err(ValidationError::TitleTooLong(141)));
+		assert_eq!(
+			request.clone().with_tags(vec!["Tag".to_owned(); 11]),
+			Err(ValidationError::TooManyTags(11))
+		);
+		assert_eq!(
+			request.clone().with_tags(vec!["Two words".to_owned()]),
+			Err(ValidationError::InvalidTag("Two words".to_owned()))
+		);
+		assert_eq!(
+			request.with_content_classification_labels(vec![ContentClassificationLabelState {
+				id: ContentClassificationLabel::MatureGame,
+				is_enabled: false,
+			}]),
+			Err(ValidationError::ReadOnlyLabel(ContentClassificationLabel::MatureGame))
+		);
+	}
+
+	#[test]
+	fn deserialize_unknown_label() {
+		let label: ContentClassificationLabel = serde_json::from_str("\"MyUnknownLabel\"").expect("Unable to parse label");
+		assert_eq!(label, ContentClassificationLabel::Unknown("MyUnknownLabel".to_owned()));
+		assert_eq!(serde_json::to_string(&label).expect("Unable to serialize label"), "\"MyUnknownLabel\"");
+
+		let label: ContentClassificationLabel = serde_json::from_str("\"Gambling\"").expect("Unable to parse label");
+		assert_eq!(label, ContentClassificationLabel::Gambling);
+	}
+}
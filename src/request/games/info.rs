@@ -1,7 +1,7 @@
 //! Game info request
 
 // Imports
-use crate::{helix_url, HelixRequest, HttpMethod};
+use crate::{helix_url, response::TemplatedImageUrl, HelixRequest, HttpMethod};
 
 /// Game info request
 ///
@@ -99,9 +99,11 @@ impl Request {
 
 impl HelixRequest for Request {
 	type Response = Vec<Game>;
+	type Extra = ();
+	type Body = ();
 
-	fn url(&self) -> url::Url {
-		let mut url = helix_url!(games);
+	fn url_with_base(&self, base: &url::Url) -> url::Url {
+		let mut url = helix_url!(base => games);
 		{
 			let mut query_pairs = url.query_pairs_mut();
 			match self {
@@ -122,11 +124,17 @@ impl HelixRequest for Request {
 #[derive(serde::Serialize, serde::Deserialize)]
 pub struct Game {
 	/// Game's box art
-	pub box_art_url: String,
+	pub box_art_url: TemplatedImageUrl,
 
 	/// Game id
 	pub id: String,
 
 	/// Game name
 	pub name: String,
+
+	/// IGDB id
+	///
+	/// If not known by twitch, this is empty.
+	#[serde(default)]
+	pub igdb_id: String,
 }
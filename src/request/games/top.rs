@@ -1 +1,124 @@
 //! Top games request
+
+// Imports
+use super::info::Game;
+use crate::{helix_url, HelixRequest, HttpMethod, PaginatedRequest};
+
+/// Top games request
+///
+/// This request uses the `/games/top` path
+/// to get the games sorted by number of current viewers,
+/// most popular first.
+///
+/// Response is a list of `[Game]s`.
+///
+/// # Examples
+/// Simple request:
+/// ```
+/// # use twitch_helix::request::games::top::Request;
+/// # use twitch_helix::HelixRequest;
+/// let mut request = Request::new();
+///
+/// let url = request.url();
+/// assert_eq!(url.host_str(), Some("api.twitch.tv"));
+/// assert_eq!(url.path(), "/helix/games/top");
+/// assert_eq!(url.query(), Some(""));
+/// ```
+///
+/// Using every argument:
+/// ```
+/// # use twitch_helix::request::games::top::Request;
+/// # use twitch_helix::HelixRequest;
+/// let mut request = Request::new()
+///   .with_first(100)
+///   .with_after("my-cursor".to_string())
+///   .with_before("my-other-cursor".to_string());
+///
+/// let url = request.url();
+/// assert_eq!(url.host_str(), Some("api.twitch.tv"));
+/// assert_eq!(url.path(), "/helix/games/top");
+/// assert_eq!(url.query(), Some("first=100&after=my-cursor&before=my-other-cursor"));
+/// ```
+#[derive(PartialEq, Eq, Clone, Default, Debug)]
+pub struct Request {
+	/// Maximum number of objects to return
+	first: Option<usize>,
+
+	/// Cursor for forward pagination
+	after: Option<String>,
+
+	/// Cursor for backward pagination
+	before: Option<String>,
+}
+
+impl Request {
+	/// Creates a new request with all parameters default
+	#[must_use]
+	pub fn new() -> Self {
+		Self {
+			first: None,
+			after: None,
+			before: None,
+		}
+	}
+
+	/// Sets the maximum number of objects to return
+	#[must_use]
+	pub fn with_first(self, first: usize) -> Self {
+		Self { first: Some(first), ..self }
+	}
+
+	/// Sets the cursor for forward pagination
+	#[must_use]
+	pub fn with_after(self, after: String) -> Self {
+		Self { after: Some(after), ..self }
+	}
+
+	/// Sets the cursor for backward pagination
+	#[must_use]
+	pub fn with_before(self, before: String) -> Self {
+		Self {
+			before: Some(before),
+			..self
+		}
+	}
+}
+
+impl HelixRequest for Request {
+	type Response = Vec<Game>;
+	type Extra = ();
+	type Body = ();
+
+	fn url_with_base(&self, base: &url::Url) -> url::Url {
+		// Append all our arguments if they exist
+		let mut url = helix_url!(base => games / top);
+
+		{
+			let mut query_pairs = url.query_pairs_mut();
+			if let Some(first) = &self.first {
+				query_pairs.append_pair("first", &first.to_string());
+			}
+			if let Some(after) = &self.after {
+				query_pairs.append_pair("after", after);
+			}
+			if let Some(before) = &self.before {
+				query_pairs.append_pair("before", before);
+			}
+		}
+		url
+	}
+
+	fn http_method(&self) -> HttpMethod {
+		HttpMethod::Get
+	}
+}
+
+impl PaginatedRequest for Request {
+	fn set_after(&mut self, after: Option<String>) {
+		self.after = after;
+	}
+
+	fn set_first(&mut self, first: Option<usize>) {
+		self.first = first;
+	}
+}
@@ -1,4 +1,12 @@
 //! OAuth requests
 
 // Modules
+pub mod authorize;
+pub mod device;
+pub mod revoke;
+pub mod scope;
+pub mod token;
 pub mod validate;
+
+// Exports
+pub use scope::Scope;
@@ -0,0 +1,135 @@
+//! OAuth authorization url
+
+// Imports
+use super::{scope, Scope};
+use crate::oauth_url;
+
+/// OAuth authorization url builder
+///
+/// This builds a url with the `/oauth2/authorize` path
+/// for a user to visit, to authorize an app using the
+/// authorization code flow.
+///
+/// Unlike other requests, this isn't an [`OAuthRequest`](crate::OAuthRequest),
+/// as the user must visit the url themselves. Once they do, they will be
+/// redirected to the redirect uri with a `code` that may be exchanged for an
+/// access token with [`token::Request::authorization_code`](super::token::Request::authorization_code).
+///
+/// # Examples
+/// Simple url:
+/// ```
+/// # use twitch_helix::request::oauth::authorize::Request;
+/// let mut request = Request::new("my-client-id", "http://localhost:3000");
+///
+/// let url = request.url();
+/// assert_eq!(url.host_str(), Some("id.twitch.tv"));
+/// assert_eq!(url.path(), "/oauth2/authorize");
+/// assert_eq!(
+///   url.query(),
+///   Some("client_id=my-client-id&redirect_uri=http%3A%2F%2Flocalhost%3A3000&response_type=code&scope=")
+/// );
+/// ```
+///
+/// Using every argument:
+/// ```
+/// # use twitch_helix::request::oauth::{authorize::Request, Scope};
+/// let mut request = Request::new("my-client-id", "http://localhost:3000")
+///   .with_scopes(vec![Scope::ChannelEditCommercial, Scope::ChannelReadEditors])
+///   .with_state("my-state")
+///   .with_force_verify(true);
+///
+/// let url = request.url();
+/// assert_eq!(url.host_str(), Some("id.twitch.tv"));
+/// assert_eq!(url.path(), "/oauth2/authorize");
+/// assert_eq!(
+///   url.query(),
+///   Some(
+///     "client_id=my-client-id&redirect_uri=http%3A%2F%2Flocalhost%3A3000&response_type=code\
+///      &scope=channel%3Aedit%3Acommercial+channel%3Aread%3Aeditors&state=my-state&force_verify=true"
+///   )
+/// );
+/// ```
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Request {
+	/// Client id
+	client_id: String,
+
+	/// Redirect uri
+	redirect_uri: String,
+
+	/// Scopes to request
+	scopes: Vec<Scope>,
+
+	/// State, returned alongside the code
+	state: Option<String>,
+
+	/// If the user should re-verify their authorization
+	force_verify: Option<bool>,
+}
+
+impl Request {
+	/// Creates a new authorization url builder given the
+	/// client id and the uri to redirect the user to.
+	#[must_use]
+	pub fn new(client_id: impl Into<String>, redirect_uri: impl Into<String>) -> Self {
+		Self {
+			client_id: client_id.into(),
+			redirect_uri: redirect_uri.into(),
+			scopes: vec![],
+			state: None,
+			force_verify: None,
+		}
+	}
+
+	/// Sets the scopes to request
+	#[must_use]
+	pub fn with_scopes(self, scopes: Vec<Scope>) -> Self {
+		Self { scopes, ..self }
+	}
+
+	/// Sets the state, returned alongside the code
+	#[must_use]
+	pub fn with_state(self, state: impl Into<String>) -> Self {
+		Self {
+			state: Some(state.into()),
+			..self
+		}
+	}
+
+	/// Sets if the user should re-verify their authorization
+	#[must_use]
+	pub fn with_force_verify(self, force_verify: bool) -> Self {
+		Self {
+			force_verify: Some(force_verify),
+			..self
+		}
+	}
+
+	/// Returns this authorization url, given the base oauth url
+	#[must_use]
+	pub fn url_with_base(&self, base: &url::Url) -> url::Url {
+		let mut url = oauth_url!(base => oauth2 / authorize);
+		{
+			let scopes = scope::join(&self.scopes);
+
+			let mut query_pairs = url.query_pairs_mut();
+			query_pairs.append_pair("client_id", &self.client_id);
+			query_pairs.append_pair("redirect_uri", &self.redirect_uri);
+			query_pairs.append_pair("response_type", "code");
+			query_pairs.append_pair("scope", &scopes);
+			if let Some(state) = &self.state {
+				query_pairs.append_pair("state", state);
+			}
+			if let Some(force_verify) = &self.force_verify {
+				query_pairs.append_pair("force_verify", &force_verify.to_string());
+			}
+		}
+		url
+	}
+
+	/// Returns this authorization url, using the default oauth url
+	#[must_use]
+	pub fn url(&self) -> url::Url {
+		self.url_with_base(&oauth_url!())
+	}
+}
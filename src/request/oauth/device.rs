@@ -0,0 +1,89 @@
+//! OAuth device code request
+
+// Imports
+use super::{scope, Scope};
+use crate::{oauth_url, HttpMethod, OAuthRequest};
+
+/// OAuth device code request
+///
+/// This request uses the `/oauth2/device` path
+/// to start the device code flow, for devices
+/// without a browser.
+///
+/// The user must then visit the verification uri
+/// and enter the user code, while the device polls
+/// for the token with [`token::Request::device_code`](super::token::Request::device_code).
+///
+/// # Examples
+/// ```
+/// # use twitch_helix::request::oauth::{device::Request, Scope};
+/// # use twitch_helix::OAuthRequest;
+/// let mut request = Request::new("my-client-id", vec![Scope::ChannelEditCommercial, Scope::ChannelReadEditors]);
+///
+/// let url = request.url();
+/// assert_eq!(url.host_str(), Some("id.twitch.tv"));
+/// assert_eq!(url.path(), "/oauth2/device");
+/// assert_eq!(
+///   url.query(),
+///   Some("client_id=my-client-id&scopes=channel%3Aedit%3Acommercial+channel%3Aread%3Aeditors")
+/// );
+/// ```
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Request {
+	/// Client id
+	client_id: String,
+
+	/// Scopes to request
+	scopes: Vec<Scope>,
+}
+
+impl Request {
+	/// Creates a new request
+	#[must_use]
+	pub fn new(client_id: impl Into<String>, scopes: Vec<Scope>) -> Self {
+		Self {
+			client_id: client_id.into(),
+			scopes,
+		}
+	}
+}
+
+/// OAuth device code response
+#[derive(PartialEq, Eq, Clone, Debug)]
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct Response {
+	/// Device code, to poll for the token with
+	pub device_code: String,
+
+	/// Number of seconds until the device code expires
+	pub expires_in: u64,
+
+	/// Number of seconds to wait between each poll
+	pub interval: u64,
+
+	/// User code, for the user to enter
+	pub user_code: String,
+
+	/// Verification uri, for the user to visit
+	pub verification_uri: String,
+}
+
+impl OAuthRequest for Request {
+	type Response = Response;
+
+	fn url_with_base(&self, base: &url::Url) -> url::Url {
+		let mut url = oauth_url!(base => oauth2 / device);
+		{
+			let scopes = scope::join(&self.scopes);
+
+			let mut query_pairs = url.query_pairs_mut();
+			query_pairs.append_pair("client_id", &self.client_id);
+			query_pairs.append_pair("scopes", &scopes);
+		}
+		url
+	}
+
+	fn http_method(&self) -> HttpMethod {
+		HttpMethod::Post
+	}
+}
@@ -0,0 +1,66 @@
+//! OAuth token revocation request
+
+// Imports
+use crate::{oauth_url, HttpMethod, OAuthRequest};
+
+/// OAuth token revocation request
+///
+/// This request uses the `/oauth2/revoke` path
+/// to revoke an access token, given the client
+/// id it was issued to.
+///
+/// The token is sent as a form body, so it doesn't
+/// appear in the url.
+///
+/// Twitch returns no data for this request.
+///
+/// # Examples
+/// ```
+/// # use twitch_helix::request::oauth::revoke::Request;
+/// # use twitch_helix::OAuthRequest;
+/// let mut request = Request::new("my-client-id", "my-token");
+///
+/// let url = request.url();
+/// assert_eq!(url.host_str(), Some("id.twitch.tv"));
+/// assert_eq!(url.path(), "/oauth2/revoke");
+/// assert_eq!(url.query(), None);
+/// assert_eq!(
+///   request.form(),
+///   Some(vec![("client_id", "my-client-id".to_owned()), ("token", "my-token".to_owned())])
+/// );
+/// ```
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Request {
+	/// Client id
+	client_id: String,
+
+	/// Token to revoke
+	token: String,
+}
+
+impl Request {
+	/// Creates a new request
+	#[must_use]
+	pub fn new(client_id: impl Into<String>, token: impl Into<String>) -> Self {
+		Self {
+			client_id: client_id.into(),
+			token: token.into(),
+		}
+	}
+}
+
+impl OAuthRequest for Request {
+	type Response = ();
+
+	fn url_with_base(&self, base: &url::Url) -> url::Url {
+		oauth_url!(base => oauth2 / revoke)
+	}
+
+	fn http_method(&self) -> HttpMethod {
+		HttpMethod::Post
+	}
+
+	fn form(&self) -> Option<Vec<(&'static str, String)>> {
+		Some(vec![("client_id", self.client_id.clone()), ("token", self.token.clone())])
+	}
+}
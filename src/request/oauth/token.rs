@@ -0,0 +1,256 @@
+//! OAuth token request
+
+// Imports
+use super::{scope, Scope};
+use crate::{oauth_url, HttpMethod, OAuthRequest};
+
+/// OAuth token request
+///
+/// This request uses the `/oauth2/token` path
+/// to get a new access token, given a client id,
+/// client secret and a grant to exchange for the
+/// token.
+///
+/// Public clients, using the device code flow, don't
+/// have a client secret.
+///
+/// All parameters are sent as a form body, so the
+/// client secret and grant don't appear in the url.
+///
+/// # Examples
+/// Client credentials:
+/// ```
+/// # use twitch_helix::request::oauth::token::Request;
+/// # use twitch_helix::OAuthRequest;
+/// let mut request = Request::client_credentials("my-client-id", "my-client-secret");
+///
+/// let url = request.url();
+/// assert_eq!(url.host_str(), Some("id.twitch.tv"));
+/// assert_eq!(url.path(), "/oauth2/token");
+/// assert_eq!(url.query(), None);
+///
+/// let form = request.form().expect("Request has no form");
+/// assert!(form.contains(&("client_secret", "my-client-secret".to_owned())));
+/// assert!(form.contains(&("grant_type", "client_credentials".to_owned())));
+/// ```
+///
+/// Authorization code:
+/// ```
+/// # use twitch_helix::request::oauth::token::Request;
+/// # use twitch_helix::OAuthRequest;
+/// let mut request = Request::authorization_code("my-client-id", "my-client-secret", "my-code", "http://localhost:3000");
+///
+/// let url = request.url();
+/// assert_eq!(url.host_str(), Some("id.twitch.tv"));
+/// assert_eq!(url.path(), "/oauth2/token");
+/// assert_eq!(url.query(), None);
+///
+/// let form = request.form().expect("Request has no form");
+/// assert!(form.contains(&("code", "my-code".to_owned())));
+/// assert!(form.contains(&("redirect_uri", "http://localhost:3000".to_owned())));
+/// ```
+///
+/// Refresh token:
+/// ```
+/// # use twitch_helix::request::oauth::token::Request;
+/// # use twitch_helix::OAuthRequest;
+/// let mut request = Request::refresh_token("my-client-id", "my-client-secret", "my-refresh-token");
+///
+/// let url = request.url();
+/// assert_eq!(url.host_str(), Some("id.twitch.tv"));
+/// assert_eq!(url.path(), "/oauth2/token");
+/// assert_eq!(url.query(), None);
+///
+/// let form = request.form().expect("Request has no form");
+/// assert!(form.contains(&("refresh_token", "my-refresh-token".to_owned())));
+/// ```
+///
+/// Device code:
+/// ```
+/// # use twitch_helix::request::oauth::{token::Request, Scope};
+/// # use twitch_helix::OAuthRequest;
+/// let mut request = Request::device_code("my-client-id", "my-device-code", vec![Scope::ChannelReadEditors]);
+///
+/// let url = request.url();
+/// assert_eq!(url.host_str(), Some("id.twitch.tv"));
+/// assert_eq!(url.path(), "/oauth2/token");
+/// assert_eq!(url.query(), None);
+///
+/// let form = request.form().expect("Request has no form");
+/// assert!(form.contains(&("device_code", "my-device-code".to_owned())));
+/// assert!(form.contains(&("scopes", "channel:read:editors".to_owned())));
+/// assert!(!form.iter().any(|(name, _)| *name == "client_secret"));
+/// ```
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub struct Request {
+	/// Client id
+	client_id: String,
+
+	/// Client secret
+	client_secret: Option<String>,
+
+	/// Grant
+	grant: Grant,
+}
+
+/// A grant to exchange for an access token
+#[derive(PartialEq, Eq, Clone, Debug)]
+pub enum Grant {
+	/// Client credentials, for an app access token
+	ClientCredentials,
+
+	/// Authorization code, for a user access token
+	///
+	/// See [`authorize::Request`](super::authorize::Request) for
+	/// getting the code.
+	AuthorizationCode {
+		/// Code
+		code: String,
+
+		/// Redirect uri used when getting the code
+		redirect_uri: String,
+	},
+
+	/// Refresh token, for a new user access token
+	RefreshToken {
+		/// Refresh token
+		refresh_token: String,
+	},
+
+	/// Device code, for a user access token
+	///
+	/// See [`device::Request`](super::device::Request) for
+	/// getting the device code.
+	DeviceCode {
+		/// Device code
+		device_code: String,
+
+		/// Scopes requested
+		scopes: Vec<Scope>,
+	},
+}
+
+impl Request {
+	/// Creates a new request for an app access token
+	/// using the client credentials flow.
+	#[must_use]
+	pub fn client_credentials(client_id: impl Into<String>, client_secret: impl Into<String>) -> Self {
+		Self {
+			client_id: client_id.into(),
+			client_secret: Some(client_secret.into()),
+			grant: Grant::ClientCredentials,
+		}
+	}
+
+	/// Creates a new request for a user access token
+	/// using the authorization code flow.
+	#[must_use]
+	pub fn authorization_code(
+		client_id: impl Into<String>, client_secret: impl Into<String>, code: impl Into<String>, redirect_uri: impl Into<String>,
+	) -> Self {
+		Self {
+			client_id: client_id.into(),
+			client_secret: Some(client_secret.into()),
+			grant: Grant::AuthorizationCode {
+				code: code.into(),
+				redirect_uri: redirect_uri.into(),
+			},
+		}
+	}
+
+	/// Creates a new request for a user access token
+	/// given a refresh token.
+	#[must_use]
+	pub fn refresh_token(client_id: impl Into<String>, client_secret: impl Into<String>, refresh_token: impl Into<String>) -> Self {
+		Self {
+			client_id: client_id.into(),
+			client_secret: Some(client_secret.into()),
+			grant: Grant::RefreshToken {
+				refresh_token: refresh_token.into(),
+			},
+		}
+	}
+
+	/// Creates a new request for a user access token
+	/// using the device code flow.
+	///
+	/// The scopes must be the same as the ones requested
+	/// for the device code.
+	#[must_use]
+	pub fn device_code(client_id: impl Into<String>, device_code: impl Into<String>, scopes: Vec<Scope>) -> Self {
+		Self {
+			client_id: client_id.into(),
+			client_secret: None,
+			grant: Grant::DeviceCode {
+				device_code: device_code.into(),
+				scopes,
+			},
+		}
+	}
+}
+
+/// OAuth token response
+#[derive(PartialEq, Eq, Clone, Debug)]
+#[derive(serde::Serialize, serde::Deserialize)]
+pub struct Response {
+	/// Access token
+	pub access_token: String,
+
+	/// Refresh token
+	///
+	/// Only returned for user access tokens.
+	pub refresh_token: Option<String>,
+
+	/// Number of seconds until the token expires
+	///
+	/// Not returned when refreshing a token.
+	pub expires_in: Option<u64>,
+
+	/// Scopes
+	///
+	/// Only returned for user access tokens.
+	#[serde(default)]
+	pub scope: Vec<Scope>,
+
+	/// Token type
+	pub token_type: String,
+}
+
+impl OAuthRequest for Request {
+	type Response = Response;
+
+	fn url_with_base(&self, base: &url::Url) -> url::Url {
+		oauth_url!(base => oauth2 / token)
+	}
+
+	fn http_method(&self) -> HttpMethod {
+		HttpMethod::Post
+	}
+
+	fn form(&self) -> Option<Vec<(&'static str, String)>> {
+		let mut form = vec![("client_id", self.client_id.clone())];
+		if let Some(client_secret) = &self.client_secret {
+			form.push(("client_secret", client_secret.clone()));
+		}
+		match &self.grant {
+			Grant::ClientCredentials => {
+				form.push(("grant_type", "client_credentials".to_owned()));
+			}
+			Grant::AuthorizationCode { code, redirect_uri } => {
+				form.push(("grant_type", "authorization_code".to_owned()));
+				form.push(("code", code.clone()));
+				form.push(("redirect_uri", redirect_uri.clone()));
+			}
+			Grant::RefreshToken { refresh_token } => {
+				form.push(("grant_type", "refresh_token".to_owned()));
+				form.push(("refresh_token", refresh_token.clone()));
+			}
+			Grant::DeviceCode { device_code, scopes } => {
+				form.push(("grant_type", "urn:ietf:params:oauth:grant-type:device_code".to_owned()));
+				form.push(("device_code", device_code.clone()));
+				form.push(("scopes", scope::join(scopes)));
+			}
+		}
+		Some(form)
+	}
+}
@@ -1,6 +1,7 @@
 //! OAuth validation request
 
 // Imports
+use super::Scope;
 use crate::{oauth_url, OAuthRequest};
 
 /// OAuth validation request
@@ -37,7 +38,7 @@ pub struct Response {
 	pub login: String,
 
 	/// Scopes
-	pub scopes: Vec<String>,
+	pub scopes: Vec<Scope>,
 
 	/// User id
 	pub user_id: String,
@@ -49,7 +50,7 @@ pub struct Response {
 impl OAuthRequest for Request {
 	type Response = Response;
 
-	fn url(&self) -> url::Url {
-		oauth_url!(oauth2 / validate)
+	fn url_with_base(&self, base: &url::Url) -> url::Url {
+		oauth_url!(base => oauth2 / validate)
 	}
 }
@@ -1,7 +1,7 @@
 //! Channel search request
 
 // Imports
-use crate::{helix_url, HelixRequest, HttpMethod};
+use crate::{helix_url, response::TemplatedImageUrl, HelixRequest, HttpMethod, PaginatedRequest};
 
 /// Channel search request
 ///
@@ -121,10 +121,12 @@ impl Request {
 
 impl HelixRequest for Request {
 	type Response = Vec<Channel>;
+	type Extra = ();
+	type Body = ();
 
-	fn url(&self) -> url::Url {
+	fn url_with_base(&self, base: &url::Url) -> url::Url {
 		// Append all our arguments if they exist
-		let mut url = helix_url!(search / channels);
+		let mut url = helix_url!(base => search / channels);
 
 		{
 			let mut query_pairs = url.query_pairs_mut();
@@ -147,6 +149,16 @@ impl HelixRequest for Request {
 	}
 }
 
+impl PaginatedRequest for Request {
+	fn set_after(&mut self, after: Option<String>) {
+		self.after = after;
+	}
+
+	fn set_first(&mut self, first: Option<usize>) {
+		self.first = first;
+	}
+}
+
 /// Each channel in the output data
 #[derive(PartialEq, Eq, Clone, Debug)]
 #[derive(serde::Serialize, serde::Deserialize)]
@@ -171,7 +183,10 @@ pub struct Channel {
 	pub tag_ids: Vec<String>,
 
 	/// Thumbnail url
-	pub thumbnail_url: String,
+	///
+	/// Twitch doesn't always return a template for these, in which
+	/// case it renders to itself.
+	pub thumbnail_url: TemplatedImageUrl,
 
 	/// Title
 	pub title: String,
@@ -181,3 +196,39 @@ pub struct Channel {
 	#[serde(with = "crate::util::utc_date_time")]
 	pub started_at: Option<chrono::DateTime<chrono::Utc>>,
 }
+
+#[cfg(test)]
+mod test {
+	// Imports
+	use super::*;
+	use crate::response::ResponseData;
+
+	#[test]
+	fn deserialize_thumbnail_without_placeholders() {
+		let response = r#"{
+			"data": [{
+				"broadcaster_language": "en",
+				"display_name": "LoL_Esports",
+				"game_id": "21779",
+				"id": "124420521",
+				"is_live": false,
+				"tag_ids": [],
+				"thumbnail_url": "https://static-cdn.jtvnw.net/jtv_user_pictures/lol-esports-profile_image-300x300.png",
+				"title": "LCS Summer",
+				"started_at": ""
+			}],
+			"pagination": {}
+		}"#;
+
+		let response: ResponseData<Vec<Channel>> = serde_json::from_str(response).expect("Unable to parse channels");
+		let thumbnail_url = &response.data[0].thumbnail_url;
+		assert_eq!(
+			thumbnail_url.as_str(),
+			"https://static-cdn.jtvnw.net/jtv_user_pictures/lol-esports-profile_image-300x300.png"
+		);
+		assert_eq!(
+			thumbnail_url.render(70, 70).expect("Unable to render thumbnail").as_str(),
+			"https://static-cdn.jtvnw.net/jtv_user_pictures/lol-esports-profile_image-300x300.png"
+		);
+	}
+}
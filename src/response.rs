@@ -4,21 +4,22 @@
 ///
 /// Every response from Helix may be an error, of type [`ResponseError`],
 /// and each successful response, is wrapped within a `data` field, as well
-/// as a possible `pagination` field for requests that may search further.
+/// as a possible `pagination` field for requests that may search further,
+/// and any extra fields.
 #[derive(PartialEq, Eq, Clone, Debug)]
 #[derive(serde::Serialize, serde::Deserialize)]
 #[serde(untagged)]
-pub enum HelixResponse<T> {
+pub enum HelixResponse<T, E = ()> {
 	/// Ok
-	Ok(ResponseData<T>),
+	Ok(ResponseData<T, E>),
 
 	/// Error
 	Err(ResponseError),
 }
 
-impl<T> HelixResponse<T> {
+impl<T, E> HelixResponse<T, E> {
 	/// Turns this response into a `Result`
-	pub fn into_result(self) -> Result<ResponseData<T>, ResponseError> {
+	pub fn into_result(self) -> Result<ResponseData<T, E>, ResponseError> {
 		match self {
 			Self::Ok(ok) => Ok(ok),
 			Self::Err(err) => Err(err),
@@ -57,14 +58,21 @@ impl<T> OAuthResponse<T> {
 /// The response data for each helix request is wrapped
 /// within a `data` field, as well as contain a `pagination`
 /// field for requests with multiple pages.
+///
+/// Some requests also return extra fields alongside these,
+/// which are kept in the `extra` field.
 #[derive(PartialEq, Eq, Clone, Debug)]
 #[derive(serde::Serialize, serde::Deserialize)]
-pub struct ResponseData<T> {
+pub struct ResponseData<T, E = ()> {
 	/// The actual data
 	pub data: T,
 
 	/// Possible pagination for the data
 	pub pagination: Option<Pagination>,
+
+	/// Extra fields
+	#[serde(flatten)]
+	pub extra: E,
 }
 
 /// Response error
@@ -92,9 +100,136 @@ pub struct ResponseError {
 /// Represents the current page from the request.
 /// May be fed into some requests to get the next page,
 /// as a linked-list.
+///
+/// On the last page, twitch returns an empty pagination,
+/// in which case `cursor` will be `None`.
 #[derive(PartialEq, Eq, Clone, Debug)]
 #[derive(serde::Serialize, serde::Deserialize)]
 pub struct Pagination {
 	/// Current cursor
-	cursor: String,
+	#[serde(default, skip_serializing_if = "Option::is_none")]
+	pub cursor: Option<String>,
+}
+
+/// Templated image url
+///
+/// Some images are returned as a url template, with `{width}` and `{height}`
+/// placeholders, that must be replaced with the size of the image wanted.
+///
+/// Templates are only validated when created with [`TemplatedImageUrl::new`],
+/// as twitch may return urls without placeholders. These render to themselves.
+///
+/// # Examples
+/// ```
+/// # use twitch_helix::response::TemplatedImageUrl;
+/// let url = TemplatedImageUrl::new("https://static-cdn.jtvnw.net/ttv-boxart/Fortnite-{width}x{height}.jpg")
+///   .expect("Invalid template");
+///
+/// assert_eq!(
+///   url.render(52, 72).expect("Invalid url").as_str(),
+///   "https://static-cdn.jtvnw.net/ttv-boxart/Fortnite-52x72.jpg"
+/// );
+/// ```
+#[derive(PartialEq, Eq, Clone, Debug)]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(from = "String", into = "String")]
+pub struct TemplatedImageUrl(String);
+
+/// Error type for [`TemplatedImageUrl::new`]
+#[derive(PartialEq, Eq, Clone, Debug)]
+#[derive(thiserror::Error)]
+pub enum TemplateError {
+	/// Missing `{width}` placeholder
+	#[error("Missing `{{width}}` placeholder")]
+	MissingWidth,
+
+	/// Missing `{height}` placeholder
+	#[error("Missing `{{height}}` placeholder")]
+	MissingHeight,
+
+	/// Rendered template isn't a valid url
+	#[error("Rendered template isn't a valid url")]
+	InvalidUrl(#[source] url::ParseError),
+}
+
+impl TemplatedImageUrl {
+	/// Creates a new templated image url
+	///
+	/// The template must contain both placeholders, and be
+	/// a valid url once they're replaced.
+	pub fn new(template: impl Into<String>) -> Result<Self, TemplateError> {
+		let template = Self(template.into());
+		template.validate()?;
+		Ok(template)
+	}
+
+	/// Checks if this template contains both placeholders, and is
+	/// a valid url once they're replaced.
+	pub fn validate(&self) -> Result<(), TemplateError> {
+		if !self.0.contains("{width}") {
+			return Err(TemplateError::MissingWidth);
+		}
+		if !self.0.contains("{height}") {
+			return Err(TemplateError::MissingHeight);
+		}
+
+		// Note: Any size renders to a valid url if this one does, as they're all digits.
+		self.render(0, 0).map(|_| ()).map_err(TemplateError::InvalidUrl)
+	}
+
+	/// Returns the template
+	#[must_use]
+	pub fn as_str(&self) -> &str {
+		&self.0
+	}
+
+	/// Renders the url of the image with a size
+	pub fn render(&self, width: u32, height: u32) -> Result<url::Url, url::ParseError> {
+		let url = self.0.replace("{width}", &width.to_string()).replace("{height}", &height.to_string());
+		url::Url::parse(&url)
+	}
+}
+
+impl From<String> for TemplatedImageUrl {
+	fn from(template: String) -> Self {
+		Self(template)
+	}
+}
+
+impl From<TemplatedImageUrl> for String {
+	fn from(url: TemplatedImageUrl) -> Self {
+		url.0
+	}
+}
+
+#[cfg(test)]
+mod test {
+	// Imports
+	use super::*;
+
+	#[test]
+	fn templated_image_url_deserialize() {
+		let url: TemplatedImageUrl = serde_json::from_str(r#""https://static-cdn.jtvnw.net/ttv-boxart/Fortnite-{width}x{height}.jpg""#)
+			.expect("Unable to parse templated image url");
+		assert_eq!(url.validate(), Ok(()));
+		assert_eq!(url.render(285, 380).expect("Invalid url").path(), "/ttv-boxart/Fortnite-285x380.jpg");
+	}
+
+	#[test]
+	fn templated_image_url_missing_placeholder() {
+		assert_eq!(
+			TemplatedImageUrl::new("https://static-cdn.jtvnw.net/ttv-boxart/Fortnite-{width}x.jpg"),
+			Err(TemplateError::MissingHeight)
+		);
+		assert_eq!(
+			TemplatedImageUrl::new("https://static-cdn.jtvnw.net/ttv-boxart/Fortnite-x{height}.jpg"),
+			Err(TemplateError::MissingWidth)
+		);
+
+		// Note: Deserializing doesn't validate, so urls without placeholders still parse.
+		let url: TemplatedImageUrl =
+			serde_json::from_str(r#""https://static-cdn.jtvnw.net/ttv-boxart/Fortnite.jpg""#).expect("Unable to parse templated image url");
+		assert_eq!(url.validate(), Err(TemplateError::MissingWidth));
+		assert_eq!(url.render(285, 380).expect("Invalid url").path(), "/ttv-boxart/Fortnite.jpg");
+	}
 }
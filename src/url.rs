@@ -11,6 +11,10 @@
 //!
 //! If you ever encounter a panic with message `"Unable to parse known url"`,
 //! report it as a bug, as it is intended for this to always succeed.
+//!
+//! Both macros may also be given a base url to build the url on top of,
+//! instead of the default twitch url, such as a local mock server.
+//! This base url must be able to be a base, see [`url::Url::cannot_be_a_base`].
 
 /// Creates a url to a helix page
 ///
@@ -19,12 +23,18 @@
 /// separated by slashes, `/`, to build the full url.
 /// There may _not_ exist a trailing `/`.
 ///
+/// The arguments may be preceded by `base =>`, where `base`
+/// is a `&url::Url` to use instead of the default helix url.
+///
 /// # Examples
 /// The following examples demonstrates how to use the macro
 /// ```
 /// # use twitch_helix::helix_url;
 /// assert_eq!( helix_url!().as_str(), "https://api.twitch.tv/helix" );
 /// assert_eq!( helix_url!(my/page).as_str(), "https://api.twitch.tv/helix/my/page" );
+///
+/// let base = url::Url::parse("http://localhost:8080/mock/helix/").unwrap();
+/// assert_eq!( helix_url!(&base => my/page).as_str(), "http://localhost:8080/mock/helix/my/page" );
 /// ```
 ///
 /// The following example doesn't compile because of the trailing `/`
@@ -42,6 +52,15 @@ macro_rules! helix_url {
 		// And parse it as a url
 		url::Url::parse(url).expect("Unable to parse known url")
 	}};
+	( $base:expr => $($path:ident)/ * ) => {{
+		// Append each `path` as a segment of the base url
+		let mut url: url::Url = $base.clone();
+		url.path_segments_mut()
+			.expect("Base url cannot be a base")
+			.pop_if_empty()
+			$( .push(stringify!($path)) )*;
+		url
+	}};
 }
 
 /// Creates a url to a oauth page
@@ -51,6 +70,9 @@ macro_rules! helix_url {
 /// separated by slashes, `/`, to build the full url.
 /// There may _not_ exist a trailing `/`.
 ///
+/// The arguments may be preceded by `base =>`, where `base`
+/// is a `&url::Url` to use instead of the default oauth url.
+///
 /// # Caveats
 /// Due to how `url` works, the base oauth url path will contain a trailing
 /// slash, `/` at the end.
@@ -61,6 +83,9 @@ macro_rules! helix_url {
 /// # use twitch_helix::oauth_url;
 /// assert_eq!( oauth_url!().as_str(), "https://id.twitch.tv/" );
 /// assert_eq!( oauth_url!(my/page).as_str(), "https://id.twitch.tv/my/page" );
+///
+/// let base = url::Url::parse("http://localhost:8080").unwrap();
+/// assert_eq!( oauth_url!(&base => my/page).as_str(), "http://localhost:8080/my/page" );
 /// ```
 ///
 /// The following example doesn't compile because of the trailing `/`
@@ -78,4 +103,13 @@ macro_rules! oauth_url {
 		// And parse it as a url
 		url::Url::parse(url).expect("Unable to parse known url")
 	}};
+	( $base:expr => $($path:ident)/ * ) => {{
+		// Append each `path` as a segment of the base url
+		let mut url: url::Url = $base.clone();
+		url.path_segments_mut()
+			.expect("Base url cannot be a base")
+			.pop_if_empty()
+			$( .push(stringify!($path)) )*;
+		url
+	}};
 }
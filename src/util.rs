@@ -96,3 +96,190 @@ pub mod utc_date_time {
 		}
 	}
 }
+
+/// Integers given as either numbers or strings [de]serializer
+pub mod string_or_int {
+	// Imports
+	use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+	use std::{fmt::Display, str::FromStr};
+
+	/// An integer as either a number or a string
+	#[derive(serde::Deserialize)]
+	#[serde(untagged)]
+	enum StringOrInt {
+		/// Number
+		Int(u64),
+
+		/// String
+		String(String),
+	}
+
+	/// Integers given as either numbers or strings deserializer
+	pub fn deserialize<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+	where
+		D: Deserializer<'de>,
+		T: FromStr + std::convert::TryFrom<u64>,
+		<T as FromStr>::Err: Display,
+		<T as std::convert::TryFrom<u64>>::Error: Display,
+	{
+		match StringOrInt::deserialize(deserializer)? {
+			StringOrInt::Int(value) => T::try_from(value).map_err(<D::Error as de::Error>::custom),
+			StringOrInt::String(value) => value.parse().map_err(<D::Error as de::Error>::custom),
+		}
+	}
+
+	/// Integers serializer
+	///
+	/// Integers are always serialized as numbers.
+	pub fn serialize<S, T>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: Serializer,
+		T: Serialize,
+	{
+		value.serialize(serializer)
+	}
+
+	#[cfg(test)]
+	mod test {
+		// Imports
+		use super::*;
+
+		#[test]
+		fn deserialize_int() {
+			let mut deserializer = serde_json::Deserializer::from_str("60");
+			let value: usize = deserialize(&mut deserializer).expect("Unable to parse integer");
+			assert_eq!(value, 60);
+		}
+
+		#[test]
+		fn deserialize_string() {
+			let mut deserializer = serde_json::Deserializer::from_str("\"60\"");
+			let value: usize = deserialize(&mut deserializer).expect("Unable to parse integer");
+			assert_eq!(value, 60);
+
+			let mut deserializer = serde_json::Deserializer::from_str("\"sixty\"");
+			assert!(deserialize::<_, usize>(&mut deserializer).is_err());
+		}
+	}
+}
+
+/// UTC date-times given as either unix timestamps or RFC 3339 strings [de]serializer
+///
+/// Empty strings, as well as the `0` timestamp, are deserialized as `None`.
+/// Date-times are always serialized as strings.
+pub mod epoch_or_utc_date_time {
+	// Imports
+	use chrono::{DateTime, TimeZone, Utc};
+	use serde::{de, Deserialize, Deserializer, Serializer};
+
+	/// A date-time as either a unix timestamp or a string
+	#[derive(serde::Deserialize)]
+	#[serde(untagged)]
+	enum EpochOrString {
+		/// Unix timestamp
+		Epoch(i64),
+
+		/// String
+		String(String),
+	}
+
+	/// UTC date-times given as either unix timestamps or strings deserializer
+	pub fn deserialize<'de, D>(deserializer: D) -> Result<Option<DateTime<Utc>>, D::Error>
+	where
+		D: Deserializer<'de>,
+	{
+		match EpochOrString::deserialize(deserializer)? {
+			EpochOrString::Epoch(0) => Ok(None),
+			EpochOrString::Epoch(timestamp) => match Utc.timestamp_opt(timestamp, 0).single() {
+				Some(date_time) => Ok(Some(date_time)),
+				None => Err(<D::Error as de::Error>::invalid_value(
+					de::Unexpected::Signed(timestamp),
+					&"a unix timestamp",
+				)),
+			},
+			EpochOrString::String(date_time) => super::utc_date_time::deserialize(de::value::StringDeserializer::new(date_time)),
+		}
+	}
+
+	/// UTC date-times serializer
+	pub fn serialize<S>(date_time: &Option<DateTime<Utc>>, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: Serializer,
+	{
+		super::utc_date_time::serialize(date_time, serializer)
+	}
+
+	#[cfg(test)]
+	mod test {
+		// Imports
+		use super::*;
+
+		#[test]
+		fn deserialize_epoch() {
+			let mut deserializer = serde_json::Deserializer::from_str("1690931298");
+			let date_time = deserialize(&mut deserializer).expect("Unable to parse utc date-time");
+			assert_eq!(date_time, Utc.timestamp_opt(1_690_931_298, 0).single());
+
+			let mut deserializer = serde_json::Deserializer::from_str("0");
+			let date_time = deserialize(&mut deserializer).expect("Unable to parse utc date-time");
+			assert_eq!(date_time, None);
+		}
+
+		#[test]
+		fn deserialize_string() {
+			let mut deserializer = serde_json::Deserializer::from_str("\"2023-08-01T23:08:18+00:00\"");
+			let date_time = deserialize(&mut deserializer).expect("Unable to parse utc date-time");
+			assert_eq!(date_time, Utc.timestamp_opt(1_690_931_298, 0).single());
+
+			let mut deserializer = serde_json::Deserializer::from_str("\"\"");
+			let date_time = deserialize(&mut deserializer).expect("Unable to parse utc date-time");
+			assert_eq!(date_time, None);
+		}
+	}
+}
+
+/// Durations in seconds [de]serializer
+///
+/// Durations may be given as either numbers or strings.
+pub mod duration_secs {
+	// Imports
+	use serde::{Deserializer, Serialize, Serializer};
+	use std::time::Duration;
+
+	/// Durations in seconds deserializer
+	pub fn deserialize<'de, D>(deserializer: D) -> Result<Duration, D::Error>
+	where
+		D: Deserializer<'de>,
+	{
+		super::string_or_int::deserialize(deserializer).map(Duration::from_secs)
+	}
+
+	/// Durations in seconds serializer
+	pub fn serialize<S>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		S: Serializer,
+	{
+		duration.as_secs().serialize(serializer)
+	}
+
+	#[cfg(test)]
+	mod test {
+		// Imports
+		use super::*;
+
+		#[test]
+		fn deserialize_secs() {
+			let mut deserializer = serde_json::Deserializer::from_str("90");
+			let duration = deserialize(&mut deserializer).expect("Unable to parse duration");
+			assert_eq!(duration, Duration::from_secs(90));
+		}
+
+		#[test]
+		fn serialize_secs() {
+			let mut buf = vec![];
+			let mut serializer = serde_json::Serializer::new(&mut buf);
+			serialize(&Duration::from_millis(90_500), &mut serializer).expect("Unable to serialize duration");
+			assert_eq!(buf, b"90");
+		}
+	}
+}